@@ -0,0 +1,343 @@
+use std::collections::HashMap;
+
+use proc_macro2::{Span, TokenStream, TokenTree};
+use quote::{quote, quote_spanned};
+use syn::{
+    braced, bracketed,
+    ext::IdentExt,
+    parenthesized,
+    parse::{Parse, ParseStream},
+    spanned::Spanned,
+    token, Expr, ExprLit, ExprUnary, Ident, Lit, LitBool, LitStr, Token, UnOp,
+};
+
+/// Expands `$crate; <json>`, where `$crate` is the path to the `json_matcher` crate as passed in by
+/// the `create_json_matcher!` wrapper macro.
+pub fn expand(input: TokenStream) -> syn::Result<TokenStream> {
+    let Input { krate, matcher } = syn::parse2::<Input>(input)?;
+    Ok(matcher.to_tokens(&krate))
+}
+
+struct Input {
+    krate: TokenStream,
+    matcher: Matcher,
+}
+
+impl Parse for Input {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut krate = TokenStream::new();
+        while !input.peek(Token![;]) {
+            krate.extend([input.parse::<TokenTree>()?]);
+        }
+        input.parse::<Token![;]>()?;
+        Ok(Input {
+            krate,
+            matcher: input.parse()?,
+        })
+    }
+}
+
+/// A single position in the JSON-like syntax accepted by `create_json_matcher!`.
+enum Matcher {
+    Null(Span),
+    Bool(LitBool),
+    Number(TokenStream),
+    String(LitStr),
+    Array(Span, Vec<Matcher>),
+    Object(Span, Vec<Field>),
+    Expr(Box<Expr>),
+}
+
+struct Field {
+    key: Key,
+    value: Matcher,
+}
+
+enum Key {
+    Literal(LitStr),
+    Ident(Ident),
+    Computed(Box<Expr>),
+}
+
+/// Returns true if the stream is positioned at the end of a value, i.e. at a separating comma or at
+/// the end of the enclosing group.
+fn at_value_end(input: ParseStream) -> bool {
+    input.is_empty() || input.peek(Token![,])
+}
+
+impl Parse for Matcher {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if input.is_empty() {
+            return Err(input.error("expected a JSON value or matcher expression"));
+        }
+
+        // nested objects and arrays, unless the group is the start of a longer expression
+        if input.peek(token::Brace) {
+            let fork = input.fork();
+            let _content;
+            braced!(_content in fork);
+            if at_value_end(&fork) {
+                let content;
+                let brace = braced!(content in input);
+                return Ok(Matcher::Object(brace.span.join(), parse_fields(&content)?));
+            }
+        }
+        if input.peek(token::Bracket) {
+            let fork = input.fork();
+            let _content;
+            bracketed!(_content in fork);
+            if at_value_end(&fork) {
+                let content;
+                let bracket = bracketed!(content in input);
+                return Ok(Matcher::Array(
+                    bracket.span.join(),
+                    parse_elements(&content)?,
+                ));
+            }
+        }
+
+        // `null` keyword
+        if input.peek(Ident::peek_any) {
+            let fork = input.fork();
+            let ident = fork.call(Ident::parse_any)?;
+            if ident == "null" && at_value_end(&fork) {
+                input.call(Ident::parse_any)?;
+                return Ok(Matcher::Null(ident.span()));
+            }
+        }
+
+        // literals standing on their own
+        if input.peek(Lit) {
+            let fork = input.fork();
+            let lit = fork.parse::<Lit>()?;
+            if at_value_end(&fork) {
+                let matcher = match lit {
+                    Lit::Str(lit) => Some(Matcher::String(lit)),
+                    Lit::Bool(lit) => Some(Matcher::Bool(lit)),
+                    Lit::Int(_) | Lit::Float(_) => Some(Matcher::Number(quote!(#lit))),
+                    _ => None,
+                };
+                if let Some(matcher) = matcher {
+                    input.parse::<Lit>()?;
+                    return Ok(matcher);
+                }
+            }
+        }
+
+        // anything else is an expression evaluating to a matcher
+        let expr = input.parse::<Expr>()?;
+        if let Expr::Unary(ExprUnary {
+            op: UnOp::Neg(_),
+            expr: inner,
+            ..
+        }) = &expr
+        {
+            if let Expr::Lit(ExprLit {
+                lit: Lit::Int(_) | Lit::Float(_),
+                ..
+            }) = inner.as_ref()
+            {
+                return Ok(Matcher::Number(quote!(#expr)));
+            }
+        }
+        Ok(Matcher::Expr(Box::new(expr)))
+    }
+}
+
+fn parse_elements(input: ParseStream) -> syn::Result<Vec<Matcher>> {
+    let mut elements = vec![];
+    while !input.is_empty() {
+        elements.push(input.parse::<Matcher>()?);
+        if input.is_empty() {
+            break;
+        }
+        input.parse::<Token![,]>()?;
+    }
+    Ok(elements)
+}
+
+fn parse_fields(input: ParseStream) -> syn::Result<Vec<Field>> {
+    let mut fields = vec![];
+    let mut seen: HashMap<String, Span> = HashMap::new();
+    while !input.is_empty() {
+        let key = input.parse::<Key>()?;
+        let colon = input.parse::<Token![:]>()?;
+        if at_value_end(input) {
+            return Err(syn::Error::new(
+                colon.span,
+                "expected a value after `:` in object field",
+            ));
+        }
+        if let Some(name) = key.static_name() {
+            if seen.insert(name.clone(), key.span()).is_some() {
+                return Err(syn::Error::new(
+                    key.span(),
+                    format!("duplicate key `{}` in object", name),
+                ));
+            }
+        }
+        let value = input.parse::<Matcher>()?;
+        fields.push(Field { key, value });
+        if input.is_empty() {
+            break;
+        }
+        input.parse::<Token![,]>()?;
+    }
+    Ok(fields)
+}
+
+impl Parse for Key {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if input.peek(LitStr) {
+            Ok(Key::Literal(input.parse()?))
+        } else if input.peek(Ident::peek_any) {
+            Ok(Key::Ident(input.call(Ident::parse_any)?))
+        } else if input.peek(token::Paren) {
+            let content;
+            parenthesized!(content in input);
+            Ok(Key::Computed(Box::new(content.parse()?)))
+        } else {
+            Err(input.error(
+                "expected object key: a string literal, an identifier, or a parenthesized expression",
+            ))
+        }
+    }
+}
+
+impl Key {
+    /// The key's name, if it is known at compile time.
+    fn static_name(&self) -> Option<String> {
+        match self {
+            Key::Literal(lit) => Some(lit.value()),
+            Key::Ident(ident) => Some(ident.unraw().to_string()),
+            Key::Computed(_) => None,
+        }
+    }
+
+    fn span(&self) -> Span {
+        match self {
+            Key::Literal(lit) => lit.span(),
+            Key::Ident(ident) => ident.span(),
+            Key::Computed(expr) => expr.span(),
+        }
+    }
+
+    fn to_tokens(&self) -> TokenStream {
+        match self {
+            Key::Literal(lit) => quote!(#lit),
+            Key::Ident(ident) => {
+                let lit = LitStr::new(&ident.unraw().to_string(), ident.span());
+                quote!(#lit)
+            }
+            Key::Computed(expr) => {
+                quote_spanned!(expr.span()=> ::core::convert::AsRef::<str>::as_ref(&(#expr)))
+            }
+        }
+    }
+}
+
+impl Matcher {
+    fn to_tokens(&self, krate: &TokenStream) -> TokenStream {
+        match self {
+            Matcher::Null(span) => quote_spanned!(*span=> #krate::NullMatcher::new()),
+            Matcher::Bool(lit) => {
+                quote_spanned!(lit.span()=> #krate::BooleanMatcher::exact(#lit))
+            }
+            Matcher::Number(tokens) => {
+                quote_spanned!(tokens.span()=> #krate::__private::serde_json::json!(#tokens))
+            }
+            Matcher::String(lit) => {
+                quote_spanned!(lit.span()=> #krate::StringMatcher::new(#lit))
+            }
+            Matcher::Array(span, elements) => {
+                let elements = elements.iter().map(|element| {
+                    let tokens = element.to_tokens(krate);
+                    quote_spanned!(element.span()=> .element(#tokens))
+                });
+                quote_spanned!(*span=> #krate::ArrayMatcher::new() #(#elements)*)
+            }
+            Matcher::Object(span, fields) => {
+                let fields = fields.iter().map(|Field { key, value }| {
+                    let key = key.to_tokens();
+                    let tokens = value.to_tokens(krate);
+                    quote_spanned!(value.span()=> .field(#key, #tokens))
+                });
+                quote_spanned!(*span=> #krate::ObjectMatcher::new() #(#fields)*)
+            }
+            Matcher::Expr(expr) => quote!(#expr),
+        }
+    }
+
+    fn span(&self) -> Span {
+        match self {
+            Matcher::Null(span) | Matcher::Array(span, _) | Matcher::Object(span, _) => *span,
+            Matcher::Bool(lit) => lit.span(),
+            Matcher::Number(tokens) => tokens.span(),
+            Matcher::String(lit) => lit.span(),
+            Matcher::Expr(expr) => expr.span(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use quote::quote;
+
+    use super::*;
+
+    fn expand_err(input: TokenStream) -> String {
+        expand(quote!(::json_matcher; #input))
+            .err()
+            .unwrap()
+            .to_string()
+    }
+
+    #[test]
+    fn test_expand_shapes() {
+        assert_eq!(
+            expand(quote!(::json_matcher; null)).unwrap().to_string(),
+            quote!(::json_matcher::NullMatcher::new()).to_string()
+        );
+        assert_eq!(
+            expand(quote!(::json_matcher; { "a": [1, true], b: "c", (key): AnyMatcher::new() }))
+                .unwrap()
+                .to_string(),
+            quote!(::json_matcher::ObjectMatcher::new()
+                .field(
+                    "a",
+                    ::json_matcher::ArrayMatcher::new()
+                        .element(::json_matcher::__private::serde_json::json!(1))
+                        .element(::json_matcher::BooleanMatcher::exact(true))
+                )
+                .field("b", ::json_matcher::StringMatcher::new("c"))
+                .field(
+                    ::core::convert::AsRef::<str>::as_ref(&(key)),
+                    AnyMatcher::new()
+                ))
+            .to_string()
+        );
+        assert_eq!(
+            expand(quote!(::json_matcher; -5)).unwrap().to_string(),
+            quote!(::json_matcher::__private::serde_json::json!(-5)).to_string()
+        );
+    }
+
+    #[test]
+    fn test_expand_errors() {
+        assert_eq!(expand_err(quote!({ "a": 1 "b": 2 })), "expected `,`");
+        assert_eq!(
+            expand_err(quote!({ "a": })),
+            "expected a value after `:` in object field"
+        );
+        assert_eq!(
+            expand_err(quote!({ "a": 1, "b": 2, "a": 3 })),
+            "duplicate key `a` in object"
+        );
+        assert_eq!(
+            expand_err(quote!({ 5: 1 })),
+            "expected object key: a string literal, an identifier, or a parenthesized expression"
+        );
+        assert_eq!(expand_err(quote!({ "a" 1 })), "expected `:`");
+        assert_eq!(expand_err(quote!([1, 2] 3)), "unexpected token");
+    }
+}
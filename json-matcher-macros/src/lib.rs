@@ -0,0 +1,18 @@
+//! Procedural macros for the [`json-matcher`](https://docs.rs/json-matcher) crate.
+//!
+//! This crate is an implementation detail of `json-matcher` and should not be depended on directly.
+//! Use the re-exported `json_matcher::create_json_matcher!` instead.
+
+use proc_macro::TokenStream;
+
+mod expand;
+
+/// Create a json matcher from JSON-like syntax with embedded matchers.
+///
+/// See `json_matcher::create_json_matcher!` for documentation and examples.
+#[proc_macro]
+pub fn create_json_matcher(input: TokenStream) -> TokenStream {
+    expand::expand(input.into())
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
@@ -0,0 +1,375 @@
+use std::cell::RefCell;
+use std::fmt::{Arguments, Display};
+use std::panic::Location;
+
+use serde_json::Value;
+
+use crate::report::actual_section;
+use crate::scope::next_scope_id;
+use crate::{JsonMatcher, JsonMatcherError, ReportConfig};
+
+thread_local! {
+    static CONTEXT: RefCell<Vec<(usize, String, String)>> = const { RefCell::new(vec![]) };
+    static SOFT_SCOPES: RefCell<Vec<SoftScope>> = const { RefCell::new(vec![]) };
+}
+
+/// Labelled information that is printed in the failure report of every [`assert_jm!`](crate::assert_jm)
+/// on the current thread while the returned guard is alive.
+///
+/// Useful in long tests that make many calls, so a failure report says which call it came from:
+///
+/// ```should_panic
+/// use serde_json::json;
+/// use json_matcher::{assert_jm, AssertionContext};
+///
+/// for (url, status) in [("/orders/1", 200), ("/orders/2", 404)] {
+///     let _context = AssertionContext::attach("request", format!("GET {url} -> {status}"));
+///     assert_jm!(json!({ "status": status }), { "status": 200 });
+/// }
+/// // Output:
+/// // Json matcher failed:
+/// //   - $.status: Expected integer 200 but got 404
+/// //
+/// // Context:
+/// //   request: GET /orders/2 -> 404
+/// //
+/// // Actual:
+/// // {
+/// //   "status": 404
+/// // }
+/// ```
+#[must_use = "the context is removed as soon as the guard is dropped"]
+pub struct AssertionContext {
+    id: usize,
+}
+
+impl AssertionContext {
+    pub fn attach<L: Into<String>, V: Display>(label: L, value: V) -> Self {
+        let id = next_scope_id();
+        CONTEXT.with(|context| {
+            context
+                .borrow_mut()
+                .push((id, label.into(), value.to_string()))
+        });
+        Self { id }
+    }
+
+    /// The label / value pairs currently attached on this thread, outermost first.
+    pub fn current() -> Vec<(String, String)> {
+        CONTEXT.with(|context| {
+            context
+                .borrow()
+                .iter()
+                .map(|(_, label, value)| (label.clone(), value.clone()))
+                .collect()
+        })
+    }
+}
+
+impl Drop for AssertionContext {
+    fn drop(&mut self) {
+        CONTEXT.with(|context| context.borrow_mut().retain(|(id, _, _)| *id != self.id));
+    }
+}
+
+struct SoftScope {
+    id: usize,
+    checks: usize,
+    failures: Vec<(&'static Location<'static>, String)>,
+}
+
+/// A scope in which failing [`assert_jm!`](crate::assert_jm) calls on the current thread are
+/// recorded instead of panicking immediately.
+///
+/// All recorded failures are reported together, each labelled with the location of the
+/// `assert_jm!` call, when [`finish`](SoftAssertions::finish) is called or the scope is dropped.
+/// See also [`jm_soft!`](crate::jm_soft) for a block form.
+///
+/// ```should_panic
+/// use serde_json::json;
+/// use json_matcher::{assert_jm, SoftAssertions};
+///
+/// let soft = SoftAssertions::new();
+/// assert_jm!(json!({ "id": 1 }), { "id": 2 });
+/// assert_jm!(json!({ "id": 3 }), { "id": 3 });
+/// assert_jm!(json!({ "id": 4 }), { "id": 5 });
+/// // panics here, reporting both failures
+/// soft.finish();
+/// ```
+#[must_use = "failures are reported when the scope is finished or dropped"]
+pub struct SoftAssertions {
+    id: usize,
+    finished: bool,
+}
+
+impl Default for SoftAssertions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SoftAssertions {
+    pub fn new() -> Self {
+        let id = next_scope_id();
+        SOFT_SCOPES.with(|scopes| {
+            scopes.borrow_mut().push(SoftScope {
+                id,
+                checks: 0,
+                failures: vec![],
+            })
+        });
+        Self {
+            id,
+            finished: false,
+        }
+    }
+
+    /// Number of failures recorded so far in this scope.
+    pub fn failure_count(&self) -> usize {
+        SOFT_SCOPES.with(|scopes| {
+            scopes
+                .borrow()
+                .iter()
+                .find(|scope| scope.id == self.id)
+                .map(|scope| scope.failures.len())
+                .unwrap_or_default()
+        })
+    }
+
+    /// Ends the scope, panicking with a combined report if any assertion in it failed.
+    #[track_caller]
+    pub fn finish(mut self) {
+        self.finished = true;
+        if let Some(report) = self.close() {
+            panic!("{}", report);
+        }
+    }
+
+    fn close(&self) -> Option<String> {
+        let scope = SOFT_SCOPES.with(|scopes| {
+            let mut scopes = scopes.borrow_mut();
+            let index = scopes.iter().position(|scope| scope.id == self.id)?;
+            Some(scopes.remove(index))
+        })?;
+        if scope.failures.is_empty() {
+            return None;
+        }
+        let sections = scope
+            .failures
+            .iter()
+            .map(|(location, report)| format!("--- {} ---{}", location, report))
+            .collect::<Vec<String>>();
+        Some(format!(
+            "\nSoft json matcher assertions failed ({} of {}):\n\n{}",
+            scope.failures.len(),
+            scope.checks,
+            sections.join("\n\n")
+        ))
+    }
+
+    /// Records the outcome of an assertion in the innermost active scope, returning false if there
+    /// is no active scope.
+    fn record(location: &'static Location<'static>, report: Option<String>) -> bool {
+        SOFT_SCOPES.with(|scopes| match scopes.borrow_mut().last_mut() {
+            Some(scope) => {
+                scope.checks += 1;
+                if let Some(report) = report {
+                    scope.failures.push((location, report));
+                }
+                true
+            }
+            None => false,
+        })
+    }
+}
+
+impl Drop for SoftAssertions {
+    fn drop(&mut self) {
+        if self.finished {
+            return;
+        }
+        let report = self.close();
+        if let Some(report) = report {
+            if !std::thread::panicking() {
+                panic!("{}", report);
+            }
+        }
+    }
+}
+
+/// Builds the report printed when an assertion fails.
+pub(crate) fn failure_report(
+    actual: &Value,
+    errors: &[JsonMatcherError],
+    message: Option<Arguments>,
+) -> String {
+    let config = ReportConfig::current();
+    let errors = config.redact_errors(errors);
+    let bullets = errors
+        .iter()
+        .map(|e| format!("  - {}", e))
+        .collect::<Vec<String>>();
+    let heading = match message {
+        Some(message) => format!("Json matcher failed: {}", message),
+        None => "Json matcher failed:".to_string(),
+    };
+    let mut report = format!("\n{}\n{}", heading, bullets.join("\n"));
+    let context = AssertionContext::current();
+    if !context.is_empty() {
+        let lines = context
+            .into_iter()
+            .map(|(label, value)| format!("  {}: {}", label, value))
+            .collect::<Vec<String>>();
+        report.push_str(&format!("\n\nContext:\n{}", lines.join("\n")));
+    }
+    report.push_str("\n\n");
+    report.push_str(&actual_section(actual, &errors, &config));
+    report
+}
+
+/// Implementation of [`assert_jm!`](crate::assert_jm).
+#[doc(hidden)]
+#[track_caller]
+pub fn assert_jm<M: JsonMatcher + ?Sized>(
+    actual: &Value,
+    expectation: &M,
+    message: Option<Arguments>,
+) {
+    let errors = expectation.json_matches(actual);
+    let report = (!errors.is_empty()).then(|| failure_report(actual, &errors, message));
+    if SoftAssertions::record(Location::caller(), report.clone()) {
+        return;
+    }
+    if let Some(report) = report {
+        panic!("{}", report);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::assert_jm;
+    use crate::test::catch_string_panic;
+
+    use super::*;
+
+    #[test]
+    fn test_assertion_context() {
+        let outer = AssertionContext::attach("scenario", "checkout");
+        {
+            let _inner = AssertionContext::attach("request", "POST /orders -> 201");
+            assert_eq!(
+                catch_string_panic(|| assert_jm!(json!({ "id": 2 }), { "id": 1 })),
+                r#"
+Json matcher failed:
+  - $.id: Expected integer 1 but got 2
+
+Context:
+  scenario: checkout
+  request: POST /orders -> 201
+
+Actual:
+{
+  "id": 2
+}"#
+            );
+        }
+        assert_eq!(
+            AssertionContext::current(),
+            vec![("scenario".to_string(), "checkout".to_string())]
+        );
+        drop(outer);
+        assert_eq!(AssertionContext::current(), vec![]);
+    }
+
+    #[test]
+    fn test_soft_assertions() {
+        let soft = SoftAssertions::new();
+        assert_jm!(json!({ "id": 1 }), { "id": 1 });
+        assert_eq!(soft.failure_count(), 0);
+        soft.finish();
+
+        let line = line!();
+        let report = catch_string_panic(|| {
+            let soft = SoftAssertions::new();
+            assert_jm!(json!({ "id": 1 }), { "id": 2 });
+            assert_jm!(json!({ "id": 3 }), { "id": 3 });
+            assert_jm!(json!({ "id": 4 }), { "id": 5 }, "fourth");
+            assert_eq!(soft.failure_count(), 2);
+            soft.finish();
+        });
+        // the column of each call depends on formatting, so only its file and line are checked
+        let report = report
+            .lines()
+            .map(|line| match line.strip_suffix(" ---") {
+                Some(location) if line.starts_with("--- ") => {
+                    format!("{} ---", &location[..location.rfind(':').unwrap()])
+                }
+                _ => line.to_string(),
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        assert_eq!(
+            report,
+            format!(
+                r#"
+Soft json matcher assertions failed (2 of 3):
+
+--- {}:{} ---
+Json matcher failed:
+  - $.id: Expected integer 2 but got 1
+
+Actual:
+{{
+  "id": 1
+}}
+
+--- {}:{} ---
+Json matcher failed: fourth
+  - $.id: Expected integer 5 but got 4
+
+Actual:
+{{
+  "id": 4
+}}"#,
+                file!(),
+                line + 3,
+                file!(),
+                line + 5
+            )
+        );
+
+        // scopes are closed even if a report is raised
+        assert_jm!(json!(1), 1);
+    }
+
+    #[test]
+    fn test_soft_assertions_out_of_order() {
+        let outer = SoftAssertions::new();
+        let inner = SoftAssertions::new();
+        // finishing the outer scope first leaves the inner one active
+        outer.finish();
+        assert_jm!(json!(1), 2);
+        assert_eq!(inner.failure_count(), 1);
+        assert!(catch_string_panic(|| inner.finish())
+            .starts_with("\nSoft json matcher assertions failed (1 of 1):"));
+        assert_eq!(SOFT_SCOPES.with(|scopes| scopes.borrow().len()), 0);
+    }
+
+    #[test]
+    fn test_jm_soft() {
+        let value = crate::jm_soft! {
+            assert_jm!(json!(1), 1);
+            2
+        };
+        assert_eq!(value, 2);
+        let report = catch_string_panic(|| {
+            crate::jm_soft! {
+                assert_jm!(json!(1), 2);
+                assert_jm!(json!(1), 3);
+            }
+        });
+        assert!(report.starts_with("\nSoft json matcher assertions failed (2 of 2):"));
+    }
+}
@@ -0,0 +1,354 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, Duration, DurationRound, NaiveDate, NaiveTime, TimeZone, Timelike, Utc};
+
+use super::Clock;
+
+/// Values accepted as a bound by the matchers in this module, converted to the instant type `T`
+/// that the matcher compares against.
+pub trait IntoBound<T> {
+    fn into_bound(self) -> T;
+}
+
+impl<Z: TimeZone> IntoBound<DateTime<Utc>> for DateTime<Z> {
+    fn into_bound(self) -> DateTime<Utc> {
+        self.with_timezone(&Utc)
+    }
+}
+
+impl IntoBound<NaiveDate> for NaiveDate {
+    fn into_bound(self) -> NaiveDate {
+        self
+    }
+}
+
+impl IntoBound<NaiveTime> for NaiveTime {
+    fn into_bound(self) -> NaiveTime {
+        self
+    }
+}
+
+/// An instant type a matcher can be bounded by.
+pub(crate) trait BoundValue: PartialOrd + Copy {
+    /// How the bound is written in error messages.
+    fn describe(&self) -> String;
+
+    /// The value moved by `by`, saturating at the ends of the type's range.
+    fn shift(self, by: Duration) -> Self;
+
+    /// The value of this type at the instant `now`.
+    fn at(now: DateTime<Utc>) -> Self;
+
+    /// The value with any fraction of a second dropped.
+    fn whole_seconds(self) -> Self;
+}
+
+impl BoundValue for DateTime<Utc> {
+    fn describe(&self) -> String {
+        self.to_rfc3339()
+    }
+
+    fn shift(self, by: Duration) -> Self {
+        self.checked_add_signed(by)
+            .unwrap_or(if by < Duration::zero() {
+                DateTime::<Utc>::MIN_UTC
+            } else {
+                DateTime::<Utc>::MAX_UTC
+            })
+    }
+
+    fn at(now: DateTime<Utc>) -> Self {
+        now
+    }
+
+    fn whole_seconds(self) -> Self {
+        self.duration_trunc(Duration::seconds(1)).unwrap_or(self)
+    }
+}
+
+impl BoundValue for NaiveDate {
+    fn describe(&self) -> String {
+        self.to_string()
+    }
+
+    fn shift(self, by: Duration) -> Self {
+        self.checked_add_signed(by)
+            .unwrap_or(if by < Duration::zero() {
+                NaiveDate::MIN
+            } else {
+                NaiveDate::MAX
+            })
+    }
+
+    fn at(now: DateTime<Utc>) -> Self {
+        now.date_naive()
+    }
+
+    fn whole_seconds(self) -> Self {
+        self
+    }
+}
+
+impl BoundValue for NaiveTime {
+    fn describe(&self) -> String {
+        self.to_string()
+    }
+
+    fn shift(self, by: Duration) -> Self {
+        // times do not wrap around midnight, so a window reaching past it is cut off there
+        match self.overflowing_add_signed(by) {
+            (shifted, 0) => shifted,
+            (_, wrapped) if wrapped < 0 => NaiveTime::from_hms_opt(0, 0, 0).unwrap(),
+            _ => NaiveTime::from_hms_nano_opt(23, 59, 59, 999_999_999).unwrap(),
+        }
+    }
+
+    fn at(now: DateTime<Utc>) -> Self {
+        now.time()
+    }
+
+    fn whole_seconds(self) -> Self {
+        self.with_nanosecond(0).unwrap_or(self)
+    }
+}
+
+/// A bound, either fixed or relative to the time a matcher is run.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum Bound<T> {
+    At(T),
+    /// Now, according to the matcher's clock, shifted by the duration.
+    FromNow(Duration),
+}
+
+/// Lower and upper bounds on the instant held by a matched string.
+#[derive(Clone)]
+pub(crate) struct Bounds<T> {
+    pub(crate) lower: Option<Bound<T>>,
+    pub(crate) lower_inclusive: bool,
+    pub(crate) upper: Option<Bound<T>>,
+    pub(crate) upper_inclusive: bool,
+    pub(crate) clock: Option<Arc<dyn Clock>>,
+    /// Compare the value and bounds to the second, as the `recent` constructors always have.
+    pub(crate) whole_seconds: bool,
+}
+
+impl<T: BoundValue> Bounds<T> {
+    pub(crate) fn new() -> Self {
+        Self {
+            lower: None,
+            lower_inclusive: true,
+            upper: None,
+            upper_inclusive: true,
+            clock: None,
+            whole_seconds: false,
+        }
+    }
+
+    /// Bounds from `now - window` to `now`, inclusive, compared to the second.
+    pub(crate) fn recent(window: Duration) -> Self {
+        Self {
+            lower: Some(Bound::FromNow(-window)),
+            upper: Some(Bound::FromNow(Duration::zero())),
+            whole_seconds: true,
+            ..Self::new()
+        }
+    }
+
+    fn now(&self) -> DateTime<Utc> {
+        match &self.clock {
+            Some(clock) => clock.now(),
+            None => super::now(),
+        }
+    }
+
+    fn resolve(bound: Option<Bound<T>>, now: &mut impl FnMut() -> DateTime<Utc>) -> Option<T> {
+        bound.map(|bound| match bound {
+            Bound::At(t) => t,
+            Bound::FromNow(offset) => T::at(now()).shift(offset),
+        })
+    }
+
+    /// The lower bound, with a relative bound resolved against the clock.
+    pub(crate) fn lower(&self) -> Option<T> {
+        Self::resolve(self.lower, &mut || self.now())
+    }
+
+    /// The upper bound, with a relative bound resolved against the clock.
+    pub(crate) fn upper(&self) -> Option<T> {
+        Self::resolve(self.upper, &mut || self.now())
+    }
+
+    /// Checks `value` against the bounds, returning an error message naming the value as `noun` if
+    /// it falls outside them.
+    pub(crate) fn check(&self, value: T, noun: &str) -> Option<String> {
+        // the clock is read at most once, so both relative bounds refer to the same instant
+        let mut now = None;
+        let mut now = || *now.get_or_insert_with(|| self.now());
+        let precision = |t: T| {
+            if self.whole_seconds {
+                t.whole_seconds()
+            } else {
+                t
+            }
+        };
+        let value = precision(value);
+        if let Some(upper) = Self::resolve(self.upper, &mut now) {
+            let (outside, relation) = if self.upper_inclusive {
+                (value > precision(upper), "after")
+            } else {
+                (value >= precision(upper), "after or equal to")
+            };
+            if outside {
+                return Some(format!(
+                    "{} is {} upper bound of {}",
+                    noun,
+                    relation,
+                    upper.describe()
+                ));
+            }
+        }
+        if let Some(lower) = Self::resolve(self.lower, &mut now) {
+            let (outside, relation) = if self.lower_inclusive {
+                (value < precision(lower), "before")
+            } else {
+                (value <= precision(lower), "before or equal to")
+            };
+            if outside {
+                return Some(format!(
+                    "{} is {} lower bound of {}",
+                    noun,
+                    relation,
+                    lower.describe()
+                ));
+            }
+        }
+        None
+    }
+}
+
+/// Returned by the `within` method of the matchers in this module, to be completed with
+/// [`of`](Within::of).
+pub struct Within<M> {
+    pub(crate) matcher: M,
+    pub(crate) tolerance: Duration,
+}
+
+/// Implements the bound builders and accessors for a matcher with a `bounds: Bounds<$instant>`
+/// field. `$noun` is how the matched value is referred to in doc comments.
+macro_rules! bound_builders {
+    ($matcher:ty, $instant:ty, $noun:literal) => {
+        impl $matcher {
+            #[doc = concat!("Requires the ", $noun, " to be strictly after `t`.")]
+            pub fn after<B: $crate::datetime::IntoBound<$instant>>(mut self, t: B) -> Self {
+                self.bounds.lower = Some($crate::datetime::Bound::At(
+                    $crate::datetime::IntoBound::into_bound(t),
+                ));
+                self.bounds.lower_inclusive = false;
+                self
+            }
+
+            #[doc = concat!("Requires the ", $noun, " to be `t` or later.")]
+            pub fn at_or_after<B: $crate::datetime::IntoBound<$instant>>(mut self, t: B) -> Self {
+                self.bounds.lower = Some($crate::datetime::Bound::At(
+                    $crate::datetime::IntoBound::into_bound(t),
+                ));
+                self.bounds.lower_inclusive = true;
+                self
+            }
+
+            #[doc = concat!("Requires the ", $noun, " to be strictly before `t`.")]
+            pub fn before<B: $crate::datetime::IntoBound<$instant>>(mut self, t: B) -> Self {
+                self.bounds.upper = Some($crate::datetime::Bound::At(
+                    $crate::datetime::IntoBound::into_bound(t),
+                ));
+                self.bounds.upper_inclusive = false;
+                self
+            }
+
+            #[doc = concat!("Requires the ", $noun, " to be `t` or earlier.")]
+            pub fn at_or_before<B: $crate::datetime::IntoBound<$instant>>(mut self, t: B) -> Self {
+                self.bounds.upper = Some($crate::datetime::Bound::At(
+                    $crate::datetime::IntoBound::into_bound(t),
+                ));
+                self.bounds.upper_inclusive = true;
+                self
+            }
+
+            #[doc = concat!("Requires the ", $noun, " to be between `start` and `end`, inclusive.")]
+            pub fn between<
+                B1: $crate::datetime::IntoBound<$instant>,
+                B2: $crate::datetime::IntoBound<$instant>,
+            >(
+                self,
+                start: B1,
+                end: B2,
+            ) -> Self {
+                self.at_or_after(start).at_or_before(end)
+            }
+
+            #[doc = concat!("Requires the ", $noun, " to be strictly between `start` and `end`.")]
+            pub fn between_exclusive<
+                B1: $crate::datetime::IntoBound<$instant>,
+                B2: $crate::datetime::IntoBound<$instant>,
+            >(
+                self,
+                start: B1,
+                end: B2,
+            ) -> Self {
+                self.after(start).before(end)
+            }
+
+            #[doc = concat!("Requires the ", $noun, " to be exactly `t`.")]
+            pub fn exactly<B: $crate::datetime::IntoBound<$instant>>(self, t: B) -> Self {
+                let t: $instant = $crate::datetime::IntoBound::into_bound(t);
+                self.between(t, t)
+            }
+
+            #[doc = concat!("Requires the ", $noun, " to be within `tolerance` of the value given to")]
+            /// [`of`](crate::datetime::Within::of).
+            pub fn within(
+                self,
+                tolerance: ::chrono::Duration,
+            ) -> $crate::datetime::Within<Self> {
+                $crate::datetime::Within {
+                    matcher: self,
+                    tolerance,
+                }
+            }
+
+            /// Reads "now" from `clock` rather than the scoped, global or system clock.
+            pub fn clock(mut self, clock: impl $crate::datetime::Clock + 'static) -> Self {
+                self.bounds.clock = Some(::std::sync::Arc::new(clock));
+                self
+            }
+
+            /// The lower bound. A bound relative to now is resolved against the clock.
+            pub fn lower_bound(&self) -> Option<$instant> {
+                self.bounds.lower()
+            }
+
+            pub fn lower_bound_inclusive(&self) -> bool {
+                self.bounds.lower_inclusive
+            }
+
+            /// The upper bound. A bound relative to now is resolved against the clock.
+            pub fn upper_bound(&self) -> Option<$instant> {
+                self.bounds.upper()
+            }
+
+            pub fn upper_bound_inclusive(&self) -> bool {
+                self.bounds.upper_inclusive
+            }
+        }
+
+        impl $crate::datetime::Within<$matcher> {
+            pub fn of<B: $crate::datetime::IntoBound<$instant>>(self, t: B) -> $matcher {
+                use $crate::datetime::bounds::BoundValue;
+                let t: $instant = $crate::datetime::IntoBound::into_bound(t);
+                self.matcher
+                    .between(t.shift(-self.tolerance), t.shift(self.tolerance))
+            }
+        }
+    };
+}
+pub(crate) use bound_builders;
@@ -0,0 +1,180 @@
+use std::cell::RefCell;
+use std::sync::{Arc, RwLock};
+
+use chrono::{DateTime, Duration, TimeZone, Utc};
+
+use super::bounds::BoundValue;
+use crate::scope::next_scope_id;
+
+static GLOBAL: RwLock<Option<Arc<dyn Clock>>> = RwLock::new(None);
+
+thread_local! {
+    static SCOPED: RefCell<Vec<(usize, Arc<dyn Clock>)>> = const { RefCell::new(vec![]) };
+}
+
+/// The source of "now" for matchers with bounds relative to the current time, such as
+/// [`DateTimeStringMatcher::recent_utc`](crate::datetime::DateTimeStringMatcher::recent_utc).
+///
+/// A matcher uses, in order of preference: the clock given to its `clock` builder, the innermost
+/// [`scoped`](Clock::scoped) clock on the current thread, the clock set with
+/// [`set_global`](Clock::set_global), or the [`SystemClock`]. The clock is read each time the
+/// matcher is run, not when it is built.
+///
+/// ```
+/// use chrono::{DateTime, Utc};
+/// use json_matcher::{assert_jm, datetime::{Clock, DateTimeStringMatcher, FixedClock}};
+/// use serde_json::json;
+///
+/// // a fixture recorded last week
+/// let fixture = json!({ "created_at": "2024-01-05T09:59:30Z" });
+///
+/// let recorded_at: DateTime<Utc> = "2024-01-05T10:00:00Z".parse().unwrap();
+/// let _clock = FixedClock::new(recorded_at).scoped();
+/// assert_jm!(fixture, { "created_at": DateTimeStringMatcher::recent_utc() });
+/// ```
+pub trait Clock: Send + Sync {
+    fn now(&self) -> DateTime<Utc>;
+
+    /// Use this clock for matchers on all threads that have no scoped clock.
+    fn set_global(self)
+    where
+        Self: Sized + 'static,
+    {
+        *GLOBAL.write().unwrap_or_else(|e| e.into_inner()) = Some(Arc::new(self));
+    }
+
+    /// Use this clock for matchers on the current thread until the returned guard is dropped.
+    fn scoped(self) -> ClockGuard
+    where
+        Self: Sized + 'static,
+    {
+        let id = next_scope_id();
+        SCOPED.with(|scoped| scoped.borrow_mut().push((id, Arc::new(self))));
+        ClockGuard { id }
+    }
+}
+
+/// The system's current time.
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
+
+/// Always the same instant.
+pub struct FixedClock {
+    now: DateTime<Utc>,
+}
+
+impl FixedClock {
+    pub fn new<Z: TimeZone>(now: DateTime<Z>) -> Self {
+        Self {
+            now: now.with_timezone(&Utc),
+        }
+    }
+}
+
+impl Clock for FixedClock {
+    fn now(&self) -> DateTime<Utc> {
+        self.now
+    }
+}
+
+/// Another clock shifted by a fixed duration.
+pub struct OffsetClock {
+    clock: Arc<dyn Clock>,
+    offset: Duration,
+}
+
+impl OffsetClock {
+    /// The system clock shifted by `offset`.
+    pub fn new(offset: Duration) -> Self {
+        Self::of(SystemClock, offset)
+    }
+
+    /// `clock` shifted by `offset`.
+    pub fn of(clock: impl Clock + 'static, offset: Duration) -> Self {
+        Self {
+            clock: Arc::new(clock),
+            offset,
+        }
+    }
+}
+
+impl Clock for OffsetClock {
+    /// The shifted time, saturating at the ends of the range chrono supports.
+    fn now(&self) -> DateTime<Utc> {
+        self.clock.now().shift(self.offset)
+    }
+}
+
+/// "Now" according to the clock that a matcher without its own clock would use on this thread.
+pub fn now() -> DateTime<Utc> {
+    if let Some((_, clock)) = SCOPED.with(|scoped| scoped.borrow().last().cloned()) {
+        return clock.now();
+    }
+    match GLOBAL.read().unwrap_or_else(|e| e.into_inner()).as_ref() {
+        Some(clock) => clock.now(),
+        None => Utc::now(),
+    }
+}
+
+/// Removes a [`scoped`](Clock::scoped) clock when dropped.
+#[must_use = "the clock is removed as soon as the guard is dropped"]
+pub struct ClockGuard {
+    id: usize,
+}
+
+impl Drop for ClockGuard {
+    fn drop(&mut self) {
+        SCOPED.with(|scoped| scoped.borrow_mut().retain(|(id, _)| *id != self.id));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clocks() {
+        let t = DateTime::parse_from_rfc3339("2024-01-05T10:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        assert_eq!(FixedClock::new(t).now(), t);
+        assert_eq!(
+            OffsetClock::of(FixedClock::new(t), Duration::days(-7)).now(),
+            t - Duration::days(7)
+        );
+        let before = Utc::now();
+        let offset = OffsetClock::new(Duration::hours(1)).now();
+        assert!(offset >= before + Duration::hours(1));
+        // shifting past the supported range saturates rather than panicking
+        assert_eq!(
+            OffsetClock::of(FixedClock::new(t), Duration::MAX).now(),
+            DateTime::<Utc>::MAX_UTC
+        );
+        assert_eq!(
+            OffsetClock::of(FixedClock::new(t), Duration::MIN).now(),
+            DateTime::<Utc>::MIN_UTC
+        );
+        {
+            let _outer = FixedClock::new(t).scoped();
+            {
+                let _inner = OffsetClock::of(FixedClock::new(t), Duration::hours(1)).scoped();
+                assert_eq!(now(), t + Duration::hours(1));
+            }
+            assert_eq!(now(), t);
+        }
+        assert!(now() >= before);
+
+        // dropping guards out of order removes the right clock
+        let outer = FixedClock::new(t).scoped();
+        let inner = FixedClock::new(t + Duration::days(1)).scoped();
+        drop(outer);
+        assert_eq!(now(), t + Duration::days(1));
+        drop(inner);
+        assert!(now() >= before);
+    }
+}
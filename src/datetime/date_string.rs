@@ -0,0 +1,117 @@
+use crate::{JsonMatcher, JsonMatcherError};
+use chrono::NaiveDate;
+use serde_json::Value;
+
+use super::{bound_builders, has_shape, Bounds};
+
+/// Matches strings holding a calendar date in `YYYY-MM-DD` form, optionally within bounds.
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use json_matcher::{assert_jm, datetime::DateStringMatcher};
+/// use serde_json::json;
+///
+/// let check_in = NaiveDate::from_ymd_opt(2024, 7, 1).unwrap();
+/// assert_jm!(json!({ "check_in": "2024-07-01", "check_out": "2024-07-04" }), {
+///     "check_in": DateStringMatcher::new().exactly(check_in),
+///     "check_out": DateStringMatcher::new().after(check_in)
+/// });
+/// ```
+pub struct DateStringMatcher {
+    bounds: Bounds<NaiveDate>,
+}
+
+impl Default for DateStringMatcher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DateStringMatcher {
+    /// Matches any date.
+    pub fn new() -> Self {
+        Self {
+            bounds: Bounds::new(),
+        }
+    }
+}
+
+bound_builders!(DateStringMatcher, NaiveDate, "date");
+
+impl JsonMatcher for DateStringMatcher {
+    fn json_matches(&self, value: &Value) -> Vec<JsonMatcherError> {
+        let Value::String(as_str) = value else {
+            return vec![JsonMatcherError::at_root("Date value needs to be a string")];
+        };
+        if !has_shape(as_str, "9999-99-99") {
+            return vec![JsonMatcherError::at_root(
+                "Could not parse string as YYYY-MM-DD date",
+            )];
+        }
+        let date = match NaiveDate::parse_from_str(as_str, "%Y-%m-%d") {
+            Ok(date) => date,
+            Err(err) => {
+                return vec![JsonMatcherError::at_root(format!(
+                    "Could not parse string as YYYY-MM-DD date: {}",
+                    err
+                ))];
+            }
+        };
+        if let Some(error) = self.bounds.check(date, "Date") {
+            return vec![JsonMatcherError::at_root(error)];
+        }
+        vec![]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::assert_jm;
+    use chrono::Duration;
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_date_string_matcher() {
+        let d = NaiveDate::from_ymd_opt(2024, 2, 28).unwrap();
+        assert_jm!(json!("2024-02-29"), DateStringMatcher::new());
+        assert_jm!(json!("2024-02-29"), DateStringMatcher::new().after(d));
+        assert_jm!(
+            json!("2024-03-01"),
+            DateStringMatcher::new().within(Duration::days(2)).of(d)
+        );
+        assert_eq!(
+            DateStringMatcher::new().json_matches(&json!(20240229)),
+            vec![JsonMatcherError::at_root("Date value needs to be a string")]
+        );
+        assert_eq!(
+            DateStringMatcher::new().json_matches(&json!("2024-2-29")),
+            vec![JsonMatcherError::at_root(
+                "Could not parse string as YYYY-MM-DD date"
+            )]
+        );
+        assert_eq!(
+            DateStringMatcher::new().json_matches(&json!("2023-02-29")),
+            vec![JsonMatcherError::at_root(
+                "Could not parse string as YYYY-MM-DD date: input is out of range"
+            )]
+        );
+        assert_eq!(
+            DateStringMatcher::new()
+                .at_or_after(d)
+                .json_matches(&json!("2024-02-27")),
+            vec![JsonMatcherError::at_root(
+                "Date is before lower bound of 2024-02-28"
+            )]
+        );
+        assert_eq!(
+            DateStringMatcher::new()
+                .before(d)
+                .json_matches(&json!("2024-02-28")),
+            vec![JsonMatcherError::at_root(
+                "Date is after or equal to upper bound of 2024-02-28"
+            )]
+        );
+    }
+}
@@ -0,0 +1,521 @@
+use std::fmt::Display;
+
+use crate::{JsonMatcher, JsonMatcherError};
+use chrono::format::ParseErrorKind;
+use chrono::{DateTime, Duration, FixedOffset, NaiveDateTime, Utc};
+use chrono_tz::Tz;
+use serde_json::Value;
+
+use super::{bound_builders, localize, parse_datetime_from_string, Bounds, OffsetPolicy};
+
+/// Matches strings holding an RFC 3339 datetime, by default in UTC, optionally within bounds.
+///
+/// ```
+/// use chrono::{DateTime, Duration, Utc};
+/// use json_matcher::{assert_jm, datetime::DateTimeStringMatcher};
+/// use serde_json::json;
+///
+/// let scheduled: DateTime<Utc> = "2024-01-05T10:00:00Z".parse().unwrap();
+/// let response = json!({
+///     "created_at": "2024-01-05T09:15:00Z",
+///     "runs_at": "2024-01-05T10:00:30Z",
+///     "expires_at": "2024-01-06T10:00:00Z"
+/// });
+///
+/// assert_jm!(response, {
+///     "created_at": DateTimeStringMatcher::new().before(scheduled),
+///     "runs_at": DateTimeStringMatcher::new().within(Duration::minutes(1)).of(scheduled),
+///     "expires_at": DateTimeStringMatcher::new().exactly(scheduled + Duration::days(1))
+/// });
+/// ```
+///
+/// Datetimes with other offsets can be matched by choosing an [`OffsetPolicy`]:
+///
+/// ```
+/// use chrono_tz::Europe::Paris;
+/// use json_matcher::{assert_jm, datetime::DateTimeStringMatcher};
+/// use serde_json::json;
+///
+/// let response = json!({
+///     "starts_at": "2024-07-01T09:00:00+02:00",
+///     "local_start": "2024-07-01T09:00:00"
+/// });
+///
+/// let starts_at: chrono::DateTime<chrono::Utc> = "2024-07-01T07:00:00Z".parse().unwrap();
+/// assert_jm!(response, {
+///     "starts_at": DateTimeStringMatcher::new().in_zone(Paris).exactly(starts_at),
+///     "local_start": DateTimeStringMatcher::new().naive_in(Paris).exactly(starts_at)
+/// });
+/// ```
+pub struct DateTimeStringMatcher {
+    bounds: Bounds<DateTime<Utc>>,
+    offset_policy: OffsetPolicy,
+    format: DateTimeFormat,
+}
+
+/// The format of the strings matched by a [`DateTimeStringMatcher`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DateTimeFormat {
+    /// RFC 3339, e.g. `2024-01-05T10:00:00Z`. The offset may be left out. This is the default.
+    Rfc3339,
+    /// RFC 2822, e.g. `Fri, 05 Jan 2024 10:00:00 +0000`.
+    Rfc2822,
+    /// The HTTP date format of RFC 9110, e.g. `Fri, 05 Jan 2024 10:00:00 GMT`.
+    HttpDate,
+    /// A chrono strftime format string. Formats without an offset (`%z`) are parsed as a local time,
+    /// which is interpreted according to the [`OffsetPolicy`].
+    Custom(String),
+}
+
+impl Default for DateTimeStringMatcher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DateTimeStringMatcher {
+    /// Matches any UTC datetime.
+    pub fn new() -> Self {
+        Self {
+            bounds: Bounds::new(),
+            offset_policy: OffsetPolicy::Utc,
+            format: DateTimeFormat::Rfc3339,
+        }
+    }
+
+    /// Matches any RFC 2822 datetime in UTC.
+    pub fn rfc2822() -> Self {
+        Self::new().with_format(DateTimeFormat::Rfc2822)
+    }
+
+    /// Matches any HTTP date, which is always in UTC.
+    pub fn http_date() -> Self {
+        Self::new().with_format(DateTimeFormat::HttpDate)
+    }
+
+    /// Matches any UTC datetime written in the given chrono strftime format, e.g. `"%d/%m/%Y %H:%M"`.
+    pub fn format<S: Into<String>>(format: S) -> Self {
+        Self::new().with_format(DateTimeFormat::Custom(format.into()))
+    }
+
+    /// Matches datetimes in the minute leading up to now.
+    pub fn recent_utc() -> Self {
+        Self::recent_utc_within(Duration::minutes(1))
+    }
+
+    /// Matches datetimes in the given window leading up to now. "Now" is read from the
+    /// [`Clock`](crate::datetime::Clock) each time the matcher is run.
+    ///
+    /// The datetime is compared to the second, so one later in the current second still matches.
+    /// Bounds set with the other builders are compared with sub-second precision.
+    pub fn recent_utc_within(window: Duration) -> Self {
+        Self {
+            bounds: Bounds::recent(window),
+            ..Self::new()
+        }
+    }
+
+    pub fn with_format(mut self, format: DateTimeFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    pub fn offset_policy(mut self, offset_policy: OffsetPolicy) -> Self {
+        self.offset_policy = offset_policy;
+        self
+    }
+
+    /// Allows datetimes with any offset. Shorthand for [`OffsetPolicy::Any`].
+    pub fn any_offset(self) -> Self {
+        self.offset_policy(OffsetPolicy::Any)
+    }
+
+    /// Requires the offset observed by the given zone. Shorthand for [`OffsetPolicy::Zone`].
+    pub fn in_zone(self, tz: Tz) -> Self {
+        self.offset_policy(OffsetPolicy::Zone(tz))
+    }
+
+    /// Requires a local time without offset, interpreted in the given zone. Shorthand for
+    /// [`OffsetPolicy::Naive`].
+    pub fn naive_in(self, tz: Tz) -> Self {
+        self.offset_policy(OffsetPolicy::Naive(tz))
+    }
+
+    /// Parses the string according to the format, returning the datetime and whether the string
+    /// carried its own offset.
+    fn parse(&self, s: &str) -> Result<(DateTime<FixedOffset>, bool), String> {
+        let naive_zone = self.offset_policy.naive_zone();
+        match &self.format {
+            DateTimeFormat::Rfc3339 => {
+                let datetime = parse_datetime_from_string(s, naive_zone.map(|tz| tz.name()))
+                    .map_err(|err| {
+                        format!("Could not parse string as rfc3339 datetime: {}", err)
+                    })?;
+                Ok((datetime, DateTime::parse_from_rfc3339(s).is_ok()))
+            }
+            DateTimeFormat::Rfc2822 => DateTime::parse_from_rfc2822(s)
+                .map(|datetime| (datetime, true))
+                .map_err(|err| format!("Could not parse string as RFC 2822 datetime: {}", err)),
+            DateTimeFormat::HttpDate => {
+                NaiveDateTime::parse_from_str(s, "%a, %d %b %Y %H:%M:%S GMT")
+                    .map(|naive| (naive.and_utc().fixed_offset(), true))
+                    .map_err(|err| format!("Could not parse string as HTTP date: {}", err))
+            }
+            DateTimeFormat::Custom(format) => {
+                let error = |err: &dyn Display| {
+                    format!(
+                        "Could not parse string as datetime with format \"{}\": {}",
+                        format, err
+                    )
+                };
+                match DateTime::parse_from_str(s, format) {
+                    Ok(datetime) => Ok((datetime, true)),
+                    // the format has no offset, so the string is a local time
+                    Err(err) if err.kind() == ParseErrorKind::NotEnough => {
+                        let naive =
+                            NaiveDateTime::parse_from_str(s, format).map_err(|err| error(&err))?;
+                        let datetime = match naive_zone {
+                            Some(tz) => localize(naive, tz).map_err(|err| error(&err))?,
+                            None => naive.and_utc().fixed_offset(),
+                        };
+                        Ok((datetime, false))
+                    }
+                    Err(err) => Err(error(&err)),
+                }
+            }
+        }
+    }
+}
+
+bound_builders!(DateTimeStringMatcher, DateTime<Utc>, "datetime");
+
+impl JsonMatcher for DateTimeStringMatcher {
+    fn json_matches(&self, value: &Value) -> Vec<JsonMatcherError> {
+        let Value::String(as_str) = value else {
+            return vec![JsonMatcherError::at_root(
+                "Datetime value needs to be a string",
+            )];
+        };
+        let (datetime, has_offset) = match self.parse(as_str) {
+            Ok(parsed) => parsed,
+            Err(err) => return vec![JsonMatcherError::at_root(err)],
+        };
+        if let Some(error) = self.offset_policy.check(&datetime, has_offset) {
+            return vec![JsonMatcherError::at_root(error)];
+        }
+        if let Some(error) = self.bounds.check(datetime.with_timezone(&Utc), "Datetime") {
+            return vec![JsonMatcherError::at_root(error)];
+        }
+        vec![]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::assert_jm;
+    use crate::datetime::{Bound, Clock, FixedClock, OffsetClock};
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_date_time_string_matcher() {
+        let lower_bound = DateTime::parse_from_rfc3339("2024-01-05T10:00:00Z")
+            .unwrap()
+            .naive_utc()
+            .and_utc();
+        let upper_bound = DateTime::parse_from_rfc3339("2024-01-05T11:00:00Z")
+            .unwrap()
+            .naive_utc()
+            .and_utc();
+        let matcher = DateTimeStringMatcher {
+            bounds: Bounds {
+                lower: Some(Bound::At(lower_bound)),
+                lower_inclusive: true,
+                upper: Some(Bound::At(upper_bound)),
+                upper_inclusive: true,
+                clock: None,
+                whole_seconds: false,
+            },
+            offset_policy: OffsetPolicy::Utc,
+            format: DateTimeFormat::Rfc3339,
+        };
+        // success cases
+        assert_jm!(json!("2024-01-05T10:00:00Z"), matcher);
+        assert_jm!(json!("2024-01-05T10:30:00Z"), matcher);
+        assert_jm!(json!("2024-01-05T11:00:00Z"), matcher);
+        // failure cases
+        assert_eq!(
+            matcher.json_matches(&json!(2)),
+            vec![JsonMatcherError::at_root(
+                "Datetime value needs to be a string"
+            )]
+        );
+        assert_eq!(
+            matcher.json_matches(&json!("bloop")),
+            vec![JsonMatcherError::at_root(
+                "Could not parse string as rfc3339 datetime: Value cannot be parsed as an RFC 3339 timestamp: input contains invalid characters"
+            )]
+        );
+        assert_eq!(
+            matcher.json_matches(&json!("2024-22-05T10:00:00Z")),
+            vec![JsonMatcherError::at_root(
+                "Could not parse string as rfc3339 datetime: Value cannot be parsed as an RFC 3339 timestamp: input is out of range"
+            )]
+        );
+        assert_eq!(
+            matcher.json_matches(&json!("2024-01-05T09:59:59Z")),
+            vec![JsonMatcherError::at_root(
+                "Datetime is before lower bound of 2024-01-05T10:00:00+00:00"
+            )]
+        );
+        assert_eq!(
+            matcher.json_matches(&json!("2024-01-05T11:00:01Z")),
+            vec![JsonMatcherError::at_root(
+                "Datetime is after upper bound of 2024-01-05T11:00:00+00:00"
+            )]
+        );
+        assert_eq!(
+            matcher.json_matches(&json!("2024-01-05T11:00:01-08:00")),
+            vec![JsonMatcherError::at_root("Datetime is not in UTC")]
+        );
+    }
+
+    #[test]
+    fn test_date_time_string_matcher_builder() {
+        let t = DateTime::parse_from_rfc3339("2024-01-05T10:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        // unbounded
+        assert_jm!(json!("1970-01-01T00:00:00Z"), DateTimeStringMatcher::new());
+        // after / at_or_after
+        assert_jm!(
+            json!("2024-01-05T10:00:01Z"),
+            DateTimeStringMatcher::new().after(t)
+        );
+        assert_eq!(
+            DateTimeStringMatcher::new()
+                .after(t)
+                .json_matches(&json!("2024-01-05T10:00:00Z")),
+            vec![JsonMatcherError::at_root(
+                "Datetime is before or equal to lower bound of 2024-01-05T10:00:00+00:00"
+            )]
+        );
+        assert_jm!(
+            json!("2024-01-05T10:00:00Z"),
+            DateTimeStringMatcher::new().at_or_after(t)
+        );
+        // before / at_or_before
+        assert_jm!(
+            json!("2024-01-05T09:59:59Z"),
+            DateTimeStringMatcher::new().before(t)
+        );
+        assert_eq!(
+            DateTimeStringMatcher::new()
+                .before(t)
+                .json_matches(&json!("2024-01-05T10:00:00Z")),
+            vec![JsonMatcherError::at_root(
+                "Datetime is after or equal to upper bound of 2024-01-05T10:00:00+00:00"
+            )]
+        );
+        assert_jm!(
+            json!("2024-01-05T10:00:00Z"),
+            DateTimeStringMatcher::new().at_or_before(t)
+        );
+        // between
+        let end = t + Duration::hours(1);
+        assert_jm!(
+            json!("2024-01-05T11:00:00Z"),
+            DateTimeStringMatcher::new().between(t, end)
+        );
+        assert_eq!(
+            DateTimeStringMatcher::new()
+                .between_exclusive(t, end)
+                .json_matches(&json!("2024-01-05T11:00:00Z")),
+            vec![JsonMatcherError::at_root(
+                "Datetime is after or equal to upper bound of 2024-01-05T11:00:00+00:00"
+            )]
+        );
+        // exactly, with sub-second precision
+        assert_jm!(
+            json!("2024-01-05T10:00:00Z"),
+            DateTimeStringMatcher::new().exactly(t)
+        );
+        assert_eq!(
+            DateTimeStringMatcher::new()
+                .exactly(t)
+                .json_matches(&json!("2024-01-05T10:00:00.001Z")),
+            vec![JsonMatcherError::at_root(
+                "Datetime is after upper bound of 2024-01-05T10:00:00+00:00"
+            )]
+        );
+        // within
+        let matcher = DateTimeStringMatcher::new()
+            .within(Duration::seconds(30))
+            .of(t);
+        assert_eq!(matcher.lower_bound(), Some(t - Duration::seconds(30)));
+        assert_eq!(matcher.upper_bound(), Some(t + Duration::seconds(30)));
+        assert!(matcher.lower_bound_inclusive() && matcher.upper_bound_inclusive());
+        assert_jm!(json!("2024-01-05T09:59:30Z"), matcher);
+        assert_eq!(
+            matcher.json_matches(&json!("2024-01-05T09:59:29Z")),
+            vec![JsonMatcherError::at_root(
+                "Datetime is before lower bound of 2024-01-05T09:59:30+00:00"
+            )]
+        );
+    }
+
+    #[test]
+    fn test_recent_utc() {
+        let now = Utc::now();
+        assert_jm!(json!(now.to_rfc3339()), DateTimeStringMatcher::recent_utc());
+        let matcher = DateTimeStringMatcher::recent_utc_within(Duration::hours(2));
+        assert_jm!(json!((now - Duration::minutes(90)).to_rfc3339()), matcher);
+        // "now" is read when the matcher is run, from the matcher's own clock first
+        let recorded_at = DateTime::parse_from_rfc3339("2024-01-05T10:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let _clock = FixedClock::new(recorded_at).scoped();
+        assert_jm!(json!("2024-01-05T09:58:00Z"), matcher);
+        // recent datetimes are compared to the second, unlike explicit bounds
+        assert_jm!(json!("2024-01-05T10:00:00.5Z"), matcher);
+        assert_eq!(
+            DateTimeStringMatcher::new()
+                .at_or_before(recorded_at)
+                .json_matches(&json!("2024-01-05T10:00:00.5Z")),
+            vec![JsonMatcherError::at_root(
+                "Datetime is after upper bound of 2024-01-05T10:00:00+00:00"
+            )]
+        );
+        assert_eq!(matcher.upper_bound(), Some(recorded_at));
+        assert_eq!(
+            matcher.lower_bound(),
+            Some(recorded_at - Duration::hours(2))
+        );
+        let matcher = DateTimeStringMatcher::recent_utc().clock(OffsetClock::of(
+            FixedClock::new(recorded_at),
+            Duration::days(1),
+        ));
+        assert_jm!(json!("2024-01-06T09:59:30Z"), matcher);
+        assert_eq!(
+            matcher.json_matches(&json!("2024-01-05T10:00:00Z")),
+            vec![JsonMatcherError::at_root(
+                "Datetime is before lower bound of 2024-01-06T09:59:00+00:00"
+            )]
+        );
+    }
+
+    #[test]
+    fn test_offset_policies() {
+        let t = DateTime::parse_from_rfc3339("2024-07-01T07:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        // any offset
+        let matcher = DateTimeStringMatcher::new().any_offset().exactly(t);
+        assert_jm!(json!("2024-07-01T09:00:00+02:00"), matcher);
+        assert_jm!(json!("2024-07-01T03:00:00-04:00"), matcher);
+        assert_jm!(json!("2024-07-01T07:00:00"), matcher);
+        // specific zone
+        let matcher = DateTimeStringMatcher::new().in_zone(chrono_tz::Europe::Paris);
+        assert_jm!(json!("2024-07-01T09:00:00+02:00"), matcher);
+        assert_jm!(json!("2024-01-01T09:00:00+01:00"), matcher);
+        assert_eq!(
+            matcher.json_matches(&json!("2024-01-01T09:00:00+02:00")),
+            vec![JsonMatcherError::at_root(
+                "Datetime offset +02:00 does not match Europe/Paris (expected +01:00)"
+            )]
+        );
+        assert_eq!(
+            matcher.json_matches(&json!("2024-01-01T09:00:00")),
+            vec![JsonMatcherError::at_root(
+                "Datetime has no offset, expected one for Europe/Paris"
+            )]
+        );
+        // naive local time in a zone
+        let matcher = DateTimeStringMatcher::new()
+            .naive_in(chrono_tz::America::New_York)
+            .exactly(t);
+        assert_jm!(json!("2024-07-01T03:00:00"), matcher);
+        assert_eq!(
+            matcher.json_matches(&json!("2024-07-01T03:00:00-04:00")),
+            vec![JsonMatcherError::at_root(
+                "Datetime has offset -04:00, expected a local time in America/New_York without offset"
+            )]
+        );
+        assert_eq!(
+            matcher.json_matches(&json!("2024-03-10T02:30:00")),
+            vec![JsonMatcherError::at_root(
+                "Could not parse string as rfc3339 datetime: Local time 2024-03-10 02:30:00 does not exist in America/New_York"
+            )]
+        );
+        // utc remains the default
+        assert_eq!(
+            DateTimeStringMatcher::new().json_matches(&json!("2024-07-01T09:00:00+02:00")),
+            vec![JsonMatcherError::at_root("Datetime is not in UTC")]
+        );
+    }
+
+    #[test]
+    fn test_formats() {
+        let t = DateTime::parse_from_rfc3339("2024-01-05T10:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        // rfc 2822
+        let matcher = DateTimeStringMatcher::rfc2822().exactly(t);
+        assert_jm!(json!("Fri, 05 Jan 2024 10:00:00 +0000"), matcher);
+        assert_jm!(json!("Fri, 5 Jan 2024 10:00:00 GMT"), matcher);
+        assert_eq!(
+            matcher.json_matches(&json!("Fri, 05 Jan 2024 11:00:00 +0100")),
+            vec![JsonMatcherError::at_root("Datetime is not in UTC")]
+        );
+        assert_eq!(
+            matcher.json_matches(&json!("2024-01-05T10:00:00Z")),
+            vec![JsonMatcherError::at_root(
+                "Could not parse string as RFC 2822 datetime: input contains invalid characters"
+            )]
+        );
+        // http date
+        let matcher = DateTimeStringMatcher::http_date().at_or_after(t);
+        assert_jm!(json!("Fri, 05 Jan 2024 10:00:00 GMT"), matcher);
+        assert_eq!(
+            matcher.json_matches(&json!("Fri, 05 Jan 2024 10:00:00 +0000")),
+            vec![JsonMatcherError::at_root(
+                "Could not parse string as HTTP date: input contains invalid characters"
+            )]
+        );
+        assert_eq!(
+            matcher.json_matches(&json!("Thu, 05 Jan 2024 10:00:00 GMT")),
+            vec![JsonMatcherError::at_root(
+                "Could not parse string as HTTP date: no possible date and time matching input"
+            )]
+        );
+        // custom format without offset
+        let matcher = DateTimeStringMatcher::format("%d/%m/%Y %H:%M").exactly(t);
+        assert_jm!(json!("05/01/2024 10:00"), matcher);
+        assert_eq!(
+            matcher.json_matches(&json!("2024-01-05 10:00")),
+            vec![JsonMatcherError::at_root(
+                "Could not parse string as datetime with format \"%d/%m/%Y %H:%M\": input contains invalid characters"
+            )]
+        );
+        assert_jm!(
+            json!("05/01/2024 11:00"),
+            DateTimeStringMatcher::format("%d/%m/%Y %H:%M")
+                .naive_in(chrono_tz::Europe::Paris)
+                .exactly(t)
+        );
+        // custom format with offset
+        let matcher = DateTimeStringMatcher::format("%d/%m/%Y %H:%M %z")
+            .any_offset()
+            .exactly(t);
+        assert_jm!(json!("05/01/2024 11:00 +0100"), matcher);
+        assert_eq!(
+            DateTimeStringMatcher::format("%d/%m/%Y %H:%M %z")
+                .in_zone(chrono_tz::Europe::Paris)
+                .json_matches(&json!("05/01/2024 11:00 +0200")),
+            vec![JsonMatcherError::at_root(
+                "Datetime offset +02:00 does not match Europe/Paris (expected +01:00)"
+            )]
+        );
+    }
+}
@@ -0,0 +1,407 @@
+use crate::{JsonMatcher, JsonMatcherError};
+use chrono::Duration;
+use serde_json::Value;
+
+const NANOS_PER_SECOND: i128 = 1_000_000_000;
+
+/// Matches strings holding an ISO 8601 duration such as `"PT15M"` or `"P1DT2H"`, optionally
+/// compared against a [`chrono::Duration`]. Go-style durations such as `"1h30m"` can be allowed with
+/// [`allow_go_style`](DurationStringMatcher::allow_go_style).
+///
+/// Years and months have no fixed length, so durations using them are rejected. Weeks are seven
+/// days and days are 24 hours. A leading `-` makes a duration negative.
+///
+/// ```
+/// use chrono::Duration;
+/// use json_matcher::{assert_jm, datetime::DurationStringMatcher};
+/// use serde_json::json;
+///
+/// assert_jm!(json!({ "ttl": "PT15M", "interval": "P1DT2H", "timeout": "1m30s" }), {
+///     "ttl": DurationStringMatcher::new().exactly(Duration::minutes(15)),
+///     "interval": DurationStringMatcher::new().at_least(Duration::days(1)),
+///     "timeout": DurationStringMatcher::new()
+///         .allow_go_style()
+///         .between(Duration::seconds(30), Duration::minutes(5))
+/// });
+/// ```
+pub struct DurationStringMatcher {
+    min: Option<Duration>,
+    max: Option<Duration>,
+    allow_go_style: bool,
+}
+
+impl Default for DurationStringMatcher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DurationStringMatcher {
+    /// Matches any ISO 8601 duration.
+    pub fn new() -> Self {
+        Self {
+            min: None,
+            max: None,
+            allow_go_style: false,
+        }
+    }
+
+    /// Also accepts Go-style durations, e.g. `"1h30m"` or `"250ms"`.
+    pub fn allow_go_style(mut self) -> Self {
+        self.allow_go_style = true;
+        self
+    }
+
+    /// Requires the duration to be exactly `duration`.
+    pub fn exactly(self, duration: Duration) -> Self {
+        self.between(duration, duration)
+    }
+
+    /// Requires the duration to be `min` or longer.
+    pub fn at_least(mut self, min: Duration) -> Self {
+        self.min = Some(min);
+        self
+    }
+
+    /// Requires the duration to be `max` or shorter.
+    pub fn at_most(mut self, max: Duration) -> Self {
+        self.max = Some(max);
+        self
+    }
+
+    /// Requires the duration to be between `min` and `max`, inclusive.
+    pub fn between(self, min: Duration, max: Duration) -> Self {
+        self.at_least(min).at_most(max)
+    }
+
+    fn parse(&self, s: &str) -> Result<Duration, String> {
+        if !self.allow_go_style || s.trim_start_matches(['-', '+']).starts_with('P') {
+            parse_iso8601(s)
+                .map_err(|err| format!("Could not parse string as ISO 8601 duration: {}", err))
+        } else {
+            parse_go(s).map_err(|err| format!("Could not parse string as Go duration: {}", err))
+        }
+    }
+}
+
+impl JsonMatcher for DurationStringMatcher {
+    fn json_matches(&self, value: &Value) -> Vec<JsonMatcherError> {
+        let Value::String(as_str) = value else {
+            return vec![JsonMatcherError::at_root(
+                "Duration value needs to be a string",
+            )];
+        };
+        let duration = match self.parse(as_str) {
+            Ok(duration) => duration,
+            Err(err) => return vec![JsonMatcherError::at_root(err)],
+        };
+        if let (Some(min), Some(max)) = (self.min, self.max) {
+            if min == max && duration != min {
+                return vec![JsonMatcherError::at_root(format!(
+                    "Expected duration {} but got {}",
+                    format_iso8601(min),
+                    as_str
+                ))];
+            }
+        }
+        if let Some(min) = self.min {
+            if duration < min {
+                return vec![JsonMatcherError::at_root(format!(
+                    "Duration {} is shorter than minimum of {}",
+                    as_str,
+                    format_iso8601(min)
+                ))];
+            }
+        }
+        if let Some(max) = self.max {
+            if duration > max {
+                return vec![JsonMatcherError::at_root(format!(
+                    "Duration {} is longer than maximum of {}",
+                    as_str,
+                    format_iso8601(max)
+                ))];
+            }
+        }
+        vec![]
+    }
+}
+
+/// Parses an ISO 8601 duration made of weeks, days, hours, minutes and seconds, in that order. Only
+/// the last component may have a fraction.
+fn parse_iso8601(s: &str) -> Result<Duration, String> {
+    let (negative, rest) = split_sign(s);
+    let Some(rest) = rest.strip_prefix('P') else {
+        return Err("expected it to start with `P`".to_string());
+    };
+    let mut nanos: i128 = 0;
+    let mut number = String::new();
+    let mut in_time = false;
+    let mut components = 0;
+    let mut last_rank = 0;
+    let mut had_fraction = false;
+    for c in rest.chars() {
+        match c {
+            '0'..='9' | '.' | ',' => number.push(if c == ',' { '.' } else { c }),
+            'T' if !in_time && number.is_empty() => in_time = true,
+            unit => {
+                let (rank, unit_nanos) = match (in_time, unit) {
+                    (false, 'Y') | (false, 'M') => {
+                        return Err("years and months have no fixed length".to_string())
+                    }
+                    (false, 'W') => (1, 7 * 86_400 * NANOS_PER_SECOND),
+                    (false, 'D') => (2, 86_400 * NANOS_PER_SECOND),
+                    (true, 'H') => (3, 3_600 * NANOS_PER_SECOND),
+                    (true, 'M') => (4, 60 * NANOS_PER_SECOND),
+                    (true, 'S') => (5, NANOS_PER_SECOND),
+                    _ => return Err(format!("unexpected `{}`", unit)),
+                };
+                if number.is_empty() {
+                    return Err(format!("missing number before `{}`", unit));
+                }
+                if rank <= last_rank {
+                    return Err(format!("`{}` is out of order", unit));
+                }
+                if had_fraction {
+                    return Err("only the last component may have a fraction".to_string());
+                }
+                had_fraction = number.contains('.');
+                nanos += scale(&number, unit_nanos)?;
+                number.clear();
+                last_rank = rank;
+                components += 1;
+            }
+        }
+    }
+    if !number.is_empty() {
+        return Err(format!("missing unit after `{}`", number));
+    }
+    if components == 0 || (in_time && last_rank < 3) {
+        return Err("expected at least one component".to_string());
+    }
+    to_duration(if negative { -nanos } else { nanos })
+}
+
+/// Parses a Go-style duration: a sequence of numbers, each followed by one of the units `ns`, `us`
+/// (or `µs`), `ms`, `s`, `m` and `h`.
+fn parse_go(s: &str) -> Result<Duration, String> {
+    let (negative, mut rest) = split_sign(s);
+    if rest == "0" {
+        return Ok(Duration::zero());
+    }
+    if rest.is_empty() {
+        return Err("expected at least one component".to_string());
+    }
+    let mut nanos: i128 = 0;
+    while !rest.is_empty() {
+        let number_len = rest
+            .find(|c: char| !c.is_ascii_digit() && c != '.')
+            .unwrap_or(rest.len());
+        let (number, after) = rest.split_at(number_len);
+        let unit_len = after
+            .find(|c: char| c.is_ascii_digit() || c == '.')
+            .unwrap_or(after.len());
+        let (unit, after) = after.split_at(unit_len);
+        if number.is_empty() {
+            return Err(format!("missing number before `{}`", unit));
+        }
+        let unit_nanos = match unit {
+            "ns" => 1,
+            "us" | "µs" | "μs" => 1_000,
+            "ms" => 1_000_000,
+            "s" => NANOS_PER_SECOND,
+            "m" => 60 * NANOS_PER_SECOND,
+            "h" => 3_600 * NANOS_PER_SECOND,
+            "" => return Err(format!("missing unit after `{}`", number)),
+            _ => return Err(format!("unknown unit `{}`", unit)),
+        };
+        nanos += scale(number, unit_nanos)?;
+        rest = after;
+    }
+    to_duration(if negative { -nanos } else { nanos })
+}
+
+fn split_sign(s: &str) -> (bool, &str) {
+    match s.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, s.strip_prefix('+').unwrap_or(s)),
+    }
+}
+
+/// `number` units, each `unit_nanos` long, in nanoseconds, keeping the precision of a fraction.
+fn scale(number: &str, unit_nanos: i128) -> Result<i128, String> {
+    let invalid = || format!("invalid number `{}`", number);
+    let (whole, fraction) = number.split_once('.').unwrap_or((number, ""));
+    if whole.is_empty() || whole.len() > 18 || fraction.len() > 18 || fraction.contains('.') {
+        return Err(invalid());
+    }
+    let whole = whole.parse::<i128>().map_err(|_| invalid())?;
+    let fraction = match fraction {
+        "" => 0,
+        digits => {
+            let scaled = digits.parse::<i128>().map_err(|_| invalid())? * unit_nanos;
+            scaled / 10_i128.pow(digits.len() as u32)
+        }
+    };
+    Ok(whole * unit_nanos + fraction)
+}
+
+fn to_duration(nanos: i128) -> Result<Duration, String> {
+    i64::try_from(nanos.div_euclid(NANOS_PER_SECOND))
+        .ok()
+        .and_then(Duration::try_seconds)
+        .map(|seconds| seconds + Duration::nanoseconds(nanos.rem_euclid(NANOS_PER_SECOND) as i64))
+        .ok_or_else(|| "duration is too long".to_string())
+}
+
+/// Writes a duration in ISO 8601 form using days, hours, minutes and seconds, e.g. `P1DT2H`.
+fn format_iso8601(duration: Duration) -> String {
+    let sign = if duration < Duration::zero() { "-" } else { "" };
+    let duration = duration.abs();
+    let days = duration.num_days();
+    let hours = duration.num_hours() % 24;
+    let minutes = duration.num_minutes() % 60;
+    let seconds = duration.num_seconds() % 60;
+    let nanos = duration.subsec_nanos();
+    let mut formatted = format!("{}P", sign);
+    if days > 0 {
+        formatted.push_str(&format!("{}D", days));
+    }
+    if hours > 0 || minutes > 0 || seconds > 0 || nanos > 0 || days == 0 {
+        formatted.push('T');
+        if hours > 0 {
+            formatted.push_str(&format!("{}H", hours));
+        }
+        if minutes > 0 {
+            formatted.push_str(&format!("{}M", minutes));
+        }
+        if nanos > 0 {
+            let fraction = format!("{:09}", nanos);
+            formatted.push_str(&format!("{}.{}S", seconds, fraction.trim_end_matches('0')));
+        } else if seconds > 0 || formatted.ends_with('T') {
+            formatted.push_str(&format!("{}S", seconds));
+        }
+    }
+    formatted
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::assert_jm;
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_parse_durations() {
+        assert_eq!(parse_iso8601("PT15M"), Ok(Duration::minutes(15)));
+        assert_eq!(
+            parse_iso8601("P1DT2H"),
+            Ok(Duration::days(1) + Duration::hours(2))
+        );
+        assert_eq!(parse_iso8601("P2W"), Ok(Duration::weeks(2)));
+        assert_eq!(parse_iso8601("PT0.5S"), Ok(Duration::milliseconds(500)));
+        assert_eq!(parse_iso8601("PT1,25H"), Ok(Duration::minutes(75)));
+        assert_eq!(parse_iso8601("-PT1M"), Ok(Duration::minutes(-1)));
+        assert_eq!(
+            parse_iso8601("P1Y"),
+            Err("years and months have no fixed length".to_string())
+        );
+        assert_eq!(
+            parse_iso8601("PT"),
+            Err("expected at least one component".to_string())
+        );
+        assert_eq!(
+            parse_iso8601("PT1S2M"),
+            Err("`M` is out of order".to_string())
+        );
+        assert_eq!(
+            parse_iso8601("PT1.5M2S"),
+            Err("only the last component may have a fraction".to_string())
+        );
+        assert_eq!(
+            parse_iso8601("PTH"),
+            Err("missing number before `H`".to_string())
+        );
+        assert_eq!(
+            parse_iso8601("P5"),
+            Err("missing unit after `5`".to_string())
+        );
+        assert_eq!(
+            parse_go("1h30m"),
+            Ok(Duration::hours(1) + Duration::minutes(30))
+        );
+        assert_eq!(parse_go("1.5h"), Ok(Duration::minutes(90)));
+        assert_eq!(
+            parse_go("2m0.25s"),
+            Ok(Duration::minutes(2) + Duration::milliseconds(250))
+        );
+        assert_eq!(parse_go("300µs"), Ok(Duration::microseconds(300)));
+        assert_eq!(parse_go("-0"), Ok(Duration::zero()));
+        assert_eq!(parse_go("5d"), Err("unknown unit `d`".to_string()));
+        assert_eq!(parse_go("5"), Err("missing unit after `5`".to_string()));
+        assert_eq!(
+            format_iso8601(Duration::days(1) + Duration::hours(2)),
+            "P1DT2H"
+        );
+        assert_eq!(format_iso8601(Duration::milliseconds(-1500)), "-PT1.5S");
+        assert_eq!(format_iso8601(Duration::zero()), "PT0S");
+        assert_eq!(format_iso8601(Duration::days(2)), "P2D");
+    }
+
+    #[test]
+    fn test_duration_string_matcher() {
+        assert_jm!(json!("PT15M"), DurationStringMatcher::new());
+        assert_jm!(
+            json!("PT900S"),
+            DurationStringMatcher::new().exactly(Duration::minutes(15))
+        );
+        assert_eq!(
+            DurationStringMatcher::new()
+                .exactly(Duration::hours(1))
+                .json_matches(&json!("PT15M")),
+            vec![JsonMatcherError::at_root(
+                "Expected duration PT1H but got PT15M"
+            )]
+        );
+        let matcher = DurationStringMatcher::new().between(Duration::minutes(1), Duration::days(1));
+        assert_eq!(
+            matcher.json_matches(&json!("PT30S")),
+            vec![JsonMatcherError::at_root(
+                "Duration PT30S is shorter than minimum of PT1M"
+            )]
+        );
+        assert_eq!(
+            matcher.json_matches(&json!("P1DT1S")),
+            vec![JsonMatcherError::at_root(
+                "Duration P1DT1S is longer than maximum of P1D"
+            )]
+        );
+        assert_eq!(
+            matcher.json_matches(&json!(60)),
+            vec![JsonMatcherError::at_root(
+                "Duration value needs to be a string"
+            )]
+        );
+        assert_eq!(
+            matcher.json_matches(&json!("1m")),
+            vec![JsonMatcherError::at_root(
+                "Could not parse string as ISO 8601 duration: expected it to start with `P`"
+            )]
+        );
+        let matcher = matcher.allow_go_style();
+        assert_jm!(json!("1m"), matcher);
+        assert_jm!(json!("PT1M"), matcher);
+        assert_eq!(
+            matcher.json_matches(&json!("1 minute")),
+            vec![JsonMatcherError::at_root(
+                "Could not parse string as Go duration: unknown unit ` minute`"
+            )]
+        );
+        assert_eq!(
+            matcher.json_matches(&json!("P1M")),
+            vec![JsonMatcherError::at_root(
+                "Could not parse string as ISO 8601 duration: years and months have no fixed length"
+            )]
+        );
+    }
+}
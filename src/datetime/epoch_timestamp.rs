@@ -0,0 +1,315 @@
+use crate::{JsonMatcher, JsonMatcherError};
+use chrono::{DateTime, Duration, Utc};
+use serde_json::{Number, Value};
+
+use super::{bound_builders, Bounds};
+
+/// The unit a unix epoch timestamp is counted in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EpochUnit {
+    Seconds,
+    Millis,
+    Micros,
+    Nanos,
+}
+
+impl EpochUnit {
+    fn nanos_per_unit(&self) -> i128 {
+        match self {
+            EpochUnit::Seconds => 1_000_000_000,
+            EpochUnit::Millis => 1_000_000,
+            EpochUnit::Micros => 1_000,
+            EpochUnit::Nanos => 1,
+        }
+    }
+
+    fn suffix(&self) -> &'static str {
+        match self {
+            EpochUnit::Seconds => "s",
+            EpochUnit::Millis => "ms",
+            EpochUnit::Micros => "µs",
+            EpochUnit::Nanos => "ns",
+        }
+    }
+}
+
+/// Matches numbers holding a unix epoch timestamp in a chosen [`EpochUnit`], optionally within
+/// bounds. Both integers and floats are accepted, and numeric strings can be allowed with
+/// [`allow_strings`](EpochTimestampMatcher::allow_strings).
+///
+/// ```
+/// use chrono::{DateTime, Utc};
+/// use json_matcher::{assert_jm, datetime::{EpochTimestampMatcher, EpochUnit}};
+/// use serde_json::json;
+///
+/// let deployed: DateTime<Utc> = "2024-06-10T16:00:00Z".parse().unwrap();
+/// assert_jm!(json!({ "created_at": 1718035200123_i64, "expires": "1718121600" }), {
+///     "created_at": EpochTimestampMatcher::millis().after(deployed),
+///     "expires": EpochTimestampMatcher::seconds().allow_strings().after(deployed)
+/// });
+/// ```
+pub struct EpochTimestampMatcher {
+    bounds: Bounds<DateTime<Utc>>,
+    unit: EpochUnit,
+    allow_strings: bool,
+}
+
+impl Default for EpochTimestampMatcher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EpochTimestampMatcher {
+    /// Matches any timestamp in seconds.
+    pub fn new() -> Self {
+        Self {
+            bounds: Bounds::new(),
+            unit: EpochUnit::Seconds,
+            allow_strings: false,
+        }
+    }
+
+    pub fn seconds() -> Self {
+        Self::new().unit(EpochUnit::Seconds)
+    }
+
+    pub fn millis() -> Self {
+        Self::new().unit(EpochUnit::Millis)
+    }
+
+    pub fn micros() -> Self {
+        Self::new().unit(EpochUnit::Micros)
+    }
+
+    pub fn nanos() -> Self {
+        Self::new().unit(EpochUnit::Nanos)
+    }
+
+    /// Matches timestamps in seconds in the minute leading up to now.
+    pub fn recent_utc() -> Self {
+        Self::recent_utc_within(Duration::minutes(1))
+    }
+
+    /// Matches timestamps in seconds in the given window leading up to now. "Now" is read from the
+    /// [`Clock`](crate::datetime::Clock) each time the matcher is run.
+    ///
+    /// The timestamp is compared to the second, so one later in the current second still matches.
+    pub fn recent_utc_within(window: Duration) -> Self {
+        Self {
+            bounds: Bounds::recent(window),
+            ..Self::new()
+        }
+    }
+
+    pub fn unit(mut self, unit: EpochUnit) -> Self {
+        self.unit = unit;
+        self
+    }
+
+    /// Also accepts strings holding the number, e.g. `"1718035200"`.
+    pub fn allow_strings(mut self) -> Self {
+        self.allow_strings = true;
+        self
+    }
+
+    /// Decodes a timestamp into an instant, rounding to the nanosecond.
+    fn decode(&self, number: &Number) -> Result<DateTime<Utc>, String> {
+        let nanos_per_unit = self.unit.nanos_per_unit();
+        let nanos = if let Some(n) = number.as_i64() {
+            Some(n as i128 * nanos_per_unit)
+        } else if let Some(n) = number.as_u64() {
+            Some(n as i128 * nanos_per_unit)
+        } else {
+            // scale the whole and fractional parts separately, so the fraction keeps its precision
+            number
+                .as_f64()
+                .filter(|n| n.is_finite() && n.abs() < i64::MAX as f64)
+                .map(|n| {
+                    n.trunc() as i128 * nanos_per_unit
+                        + (n.fract() * nanos_per_unit as f64).round() as i128
+                })
+        };
+        nanos
+            .and_then(|nanos| {
+                let secs = i64::try_from(nanos.div_euclid(1_000_000_000)).ok()?;
+                DateTime::from_timestamp(secs, nanos.rem_euclid(1_000_000_000) as u32)
+            })
+            .ok_or_else(|| {
+                format!(
+                    "Timestamp {} {} is out of range",
+                    number,
+                    self.unit.suffix()
+                )
+            })
+    }
+}
+
+bound_builders!(EpochTimestampMatcher, DateTime<Utc>, "timestamp");
+
+impl JsonMatcher for EpochTimestampMatcher {
+    fn json_matches(&self, value: &Value) -> Vec<JsonMatcherError> {
+        let number = match value {
+            Value::Number(number) => number.clone(),
+            Value::String(as_str) if self.allow_strings => {
+                let parsed = match as_str.parse::<i64>() {
+                    Ok(n) => Some(Number::from(n)),
+                    Err(_) => as_str.parse::<f64>().ok().and_then(Number::from_f64),
+                };
+                match parsed {
+                    Some(number) => number,
+                    None => {
+                        return vec![JsonMatcherError::at_root(format!(
+                            "Could not parse string \"{}\" as a timestamp",
+                            as_str
+                        ))];
+                    }
+                }
+            }
+            _ if self.allow_strings => {
+                return vec![JsonMatcherError::at_root(
+                    "Timestamp value needs to be a number or a numeric string",
+                )];
+            }
+            _ => {
+                return vec![JsonMatcherError::at_root(
+                    "Timestamp value needs to be a number",
+                )];
+            }
+        };
+        let datetime = match self.decode(&number) {
+            Ok(datetime) => datetime,
+            Err(err) => return vec![JsonMatcherError::at_root(err)],
+        };
+        let noun = format!(
+            "Timestamp {} {} ({})",
+            number,
+            self.unit.suffix(),
+            datetime.to_rfc3339()
+        );
+        if let Some(error) = self.bounds.check(datetime, &noun) {
+            return vec![JsonMatcherError::at_root(error)];
+        }
+        vec![]
+    }
+}
+
+/// Matches the unix timestamp embedded in an identifier, as a number of milliseconds, against
+/// `matcher`, labelling its errors with the kind of identifier. Used by the `timestamp` builders
+/// of the ID matchers, such as [`UuidMatcher::timestamp`](crate::UuidMatcher::timestamp).
+pub(crate) fn match_embedded_timestamp(
+    matcher: &dyn JsonMatcher,
+    millis: u64,
+    kind: &str,
+) -> Vec<JsonMatcherError> {
+    matcher
+        .json_matches(&Value::from(millis))
+        .into_iter()
+        .map(|JsonMatcherError { path, message }| JsonMatcherError {
+            path,
+            message: format!("{} timestamp: {}", kind, message),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::assert_jm;
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_epoch_timestamp_matcher() {
+        let t = DateTime::parse_from_rfc3339("2024-06-10T16:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        // units
+        assert_jm!(
+            json!(1718035200),
+            EpochTimestampMatcher::seconds().exactly(t)
+        );
+        assert_jm!(
+            json!(1718035200.0),
+            EpochTimestampMatcher::seconds().exactly(t)
+        );
+        assert_jm!(
+            json!(1718035200000_i64),
+            EpochTimestampMatcher::millis().exactly(t)
+        );
+        assert_jm!(
+            json!(1718035200000000_i64),
+            EpochTimestampMatcher::micros().exactly(t)
+        );
+        assert_jm!(
+            json!(1718035200000000000_u64),
+            EpochTimestampMatcher::nanos().exactly(t)
+        );
+        assert_jm!(
+            json!(1718035200.25),
+            EpochTimestampMatcher::seconds().exactly(t + Duration::milliseconds(250))
+        );
+        assert_jm!(
+            json!(-1),
+            EpochTimestampMatcher::seconds().before(DateTime::UNIX_EPOCH)
+        );
+        // bounds report the decoded instant
+        assert_eq!(
+            EpochTimestampMatcher::millis()
+                .at_or_after(t)
+                .json_matches(&json!(1718035199999_i64)),
+            vec![JsonMatcherError::at_root(
+                "Timestamp 1718035199999 ms (2024-06-10T15:59:59.999+00:00) is before lower bound of 2024-06-10T16:00:00+00:00"
+            )]
+        );
+        assert_eq!(
+            EpochTimestampMatcher::seconds()
+                .before(t)
+                .json_matches(&json!(1718035200)),
+            vec![JsonMatcherError::at_root(
+                "Timestamp 1718035200 s (2024-06-10T16:00:00+00:00) is after or equal to upper bound of 2024-06-10T16:00:00+00:00"
+            )]
+        );
+        // strings
+        assert_eq!(
+            EpochTimestampMatcher::seconds().json_matches(&json!("1718035200")),
+            vec![JsonMatcherError::at_root(
+                "Timestamp value needs to be a number"
+            )]
+        );
+        let matcher = EpochTimestampMatcher::seconds().allow_strings().exactly(t);
+        assert_jm!(json!("1718035200"), matcher);
+        assert_jm!(json!("1718035200.0"), matcher);
+        assert_eq!(
+            matcher.json_matches(&json!("soon")),
+            vec![JsonMatcherError::at_root(
+                "Could not parse string \"soon\" as a timestamp"
+            )]
+        );
+        assert_eq!(
+            matcher.json_matches(&json!("inf")),
+            vec![JsonMatcherError::at_root(
+                "Could not parse string \"inf\" as a timestamp"
+            )]
+        );
+        assert_eq!(
+            matcher.json_matches(&json!(true)),
+            vec![JsonMatcherError::at_root(
+                "Timestamp value needs to be a number or a numeric string"
+            )]
+        );
+        // out of range
+        assert_eq!(
+            EpochTimestampMatcher::seconds().json_matches(&json!(1e300)),
+            vec![JsonMatcherError::at_root(
+                "Timestamp 1e300 s is out of range"
+            )]
+        );
+        // recent
+        let now = Utc::now();
+        assert_jm!(
+            json!(now.timestamp_millis()),
+            EpochTimestampMatcher::recent_utc().unit(EpochUnit::Millis)
+        );
+    }
+}
@@ -0,0 +1,128 @@
+use chrono::{DateTime, FixedOffset, NaiveDateTime, Offset, TimeZone};
+use chrono_tz::Tz;
+
+mod bounds;
+pub use bounds::*;
+mod clock;
+pub use clock::*;
+mod date_time_string;
+pub use date_time_string::*;
+mod date_string;
+pub use date_string::*;
+mod time_string;
+pub use time_string::*;
+mod epoch_timestamp;
+pub use epoch_timestamp::*;
+mod relations;
+pub use relations::*;
+mod duration_string;
+pub use duration_string::*;
+
+fn parse_datetime_from_string(
+    s: &str,
+    timezone: Option<&str>,
+) -> Result<DateTime<FixedOffset>, String> {
+    let datetime = match DateTime::parse_from_rfc3339(s) {
+        Ok(x) => x,
+        Err(e) => {
+            // if original parse fails, this might be because value does not have its own timezone, which parse_from_rfc3339 expects
+            // try to add UTC timezone first.
+            let parsed = DateTime::parse_from_rfc3339(&(s.to_owned() + "Z")).map_err(|_| {
+                // if this fails, then the value is not a valid RFC 3339 timestamp
+                // return original error
+                format!("Value cannot be parsed as an RFC 3339 timestamp: {e}")
+            })?;
+            // this succeeded, now if type has a timezone, we need to calculate the offset
+            let corrected = match timezone.as_ref() {
+                None => parsed,
+                Some(tz) => match tz.parse::<Tz>() {
+                    Ok(tz) => {
+                        // the timezone string in the type is a valid timezone name
+                        // so we interpret the original parsed value as if it were already in this timezone.
+                        localize(parsed.naive_utc(), tz)?
+                    }
+                    Err(_) => {
+                        // the timezone string in the type is not a valid timezone name
+                        // so just return the utc-parsed value
+                        parsed
+                    }
+                },
+            };
+            corrected
+        }
+    };
+    Ok(datetime)
+}
+
+/// Interprets a local time in the given zone. A local time repeated by a DST transition resolves to
+/// its earlier instant.
+fn localize(naive: NaiveDateTime, tz: Tz) -> Result<DateTime<FixedOffset>, String> {
+    naive
+        .and_local_timezone(tz)
+        .earliest()
+        .map(|datetime| datetime.fixed_offset())
+        .ok_or_else(|| format!("Local time {} does not exist in {}", naive, tz))
+}
+
+/// Whether `s` has the shape of `pattern`, where `9` stands for any ASCII digit and every other
+/// character stands for itself.
+fn has_shape(s: &str, pattern: &str) -> bool {
+    s.len() == pattern.len()
+        && s.bytes().zip(pattern.bytes()).all(|(c, p)| match p {
+            b'9' => c.is_ascii_digit(),
+            _ => c == p,
+        })
+}
+
+/// Which UTC offsets a matched datetime string may carry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OffsetPolicy {
+    /// The offset must be zero. Strings without an offset are taken to be in UTC. This is the default.
+    Utc,
+    /// The offset must be the one the given IANA zone observes at that instant.
+    Zone(Tz),
+    /// Any offset is allowed. Strings without an offset are taken to be in UTC.
+    Any,
+    /// The string must not have an offset, and is interpreted as a local time in the given zone.
+    Naive(Tz),
+}
+
+impl OffsetPolicy {
+    /// The zone in which strings without an offset are interpreted, if not UTC.
+    fn naive_zone(&self) -> Option<Tz> {
+        match self {
+            OffsetPolicy::Naive(tz) => Some(*tz),
+            _ => None,
+        }
+    }
+
+    /// Checks a parsed datetime against the policy, returning an error message if it does not comply.
+    fn check(&self, datetime: &DateTime<FixedOffset>, has_offset: bool) -> Option<String> {
+        match self {
+            OffsetPolicy::Utc => (datetime.offset().utc_minus_local() != 0)
+                .then(|| "Datetime is not in UTC".to_string()),
+            OffsetPolicy::Zone(tz) => {
+                if !has_offset {
+                    return Some(format!("Datetime has no offset, expected one for {}", tz));
+                }
+                let expected = tz.offset_from_utc_datetime(&datetime.naive_utc()).fix();
+                (expected != *datetime.offset()).then(|| {
+                    format!(
+                        "Datetime offset {} does not match {} (expected {})",
+                        datetime.offset(),
+                        tz,
+                        expected
+                    )
+                })
+            }
+            OffsetPolicy::Any => None,
+            OffsetPolicy::Naive(tz) => has_offset.then(|| {
+                format!(
+                    "Datetime has offset {}, expected a local time in {} without offset",
+                    datetime.offset(),
+                    tz
+                )
+            }),
+        }
+    }
+}
@@ -0,0 +1,663 @@
+use std::fmt::Display;
+
+use crate::{JsonMatcher, JsonMatcherError, JsonPath, JsonPathElement};
+use chrono::{DateTime, Duration, FixedOffset};
+use serde_json::Value;
+
+use super::parse_datetime_from_string;
+
+/// Asserts relationships between RFC 3339 datetimes at different paths of one value, such as one
+/// timestamp being after another, two being a fixed duration apart, or a list of them increasing.
+///
+/// Paths are dot-separated keys and array indices relative to the matched value, in which `*`
+/// stands for every element of an array or object. Keys containing `.` or `[` can be written in
+/// brackets as JSON strings, as in `$["created.at"]`. A path that matches no datetimes is an error,
+/// and a malformed path panics when the relation is added. Errors are reported at the path of the
+/// offending datetime. An optional [`matching`](DateTimeRelations::matching) matcher checks the
+/// rest of the value.
+///
+/// ```
+/// use chrono::Duration;
+/// use json_matcher::{assert_jm, create_json_matcher, datetime::DateTimeRelations, AnyMatcher};
+/// use serde_json::json;
+///
+/// let token = json!({
+///     "issued_at": "2024-01-05T10:00:00Z",
+///     "expires_at": "2024-01-05T11:00:00Z",
+///     "refreshes": [
+///         { "at": "2024-01-05T10:20:00Z" },
+///         { "at": "2024-01-05T10:40:00Z" }
+///     ]
+/// });
+///
+/// assert_jm!(
+///     token,
+///     DateTimeRelations::new()
+///         .gap("issued_at", "expires_at", Duration::hours(1))
+///         .increasing("refreshes.*.at")
+///         .after("refreshes.*.at", "issued_at")
+///         .matching(create_json_matcher!({
+///             "issued_at": AnyMatcher::not_null(),
+///             "expires_at": AnyMatcher::not_null(),
+///             "refreshes": AnyMatcher::not_null()
+///         }))
+/// );
+/// ```
+pub struct DateTimeRelations {
+    matcher: Option<Box<dyn JsonMatcher>>,
+    relations: Vec<Relation>,
+}
+
+enum Relation {
+    Order {
+        path: RelationPath,
+        other: RelationPath,
+        order: Order,
+    },
+    Gap {
+        from: RelationPath,
+        to: RelationPath,
+        min: Duration,
+        max: Duration,
+    },
+    Sequence {
+        path: RelationPath,
+        strict: bool,
+    },
+}
+
+/// A parsed path of [`DateTimeRelations`].
+struct RelationPath(Vec<Segment>);
+
+enum Segment {
+    /// An object key, or an array index if it is made of digits.
+    Key(String),
+    Wildcard,
+}
+
+impl RelationPath {
+    /// Parses a path such as `events.*.at` or `$["created.at"]`, panicking if it is malformed.
+    fn parse(path: &str) -> Self {
+        let invalid =
+            |reason: &str| -> ! { panic!("Invalid datetime path {:?}: {}", path, reason) };
+        let mut rest = path.strip_prefix('$').unwrap_or(path);
+        let mut needs_dot = rest.len() < path.len();
+        let mut segments = vec![];
+        while !rest.is_empty() {
+            if let Some(bracketed) = rest.strip_prefix('[') {
+                let (segment, after) =
+                    parse_bracketed(bracketed).unwrap_or_else(|| invalid("malformed brackets"));
+                segments.push(segment);
+                rest = after;
+            } else {
+                let plain = match rest.strip_prefix('.') {
+                    Some(plain) => plain,
+                    None if needs_dot => invalid("expected `.` or `[` between segments"),
+                    None => rest,
+                };
+                let end = plain.find(['.', '[']).unwrap_or(plain.len());
+                let segment = match &plain[..end] {
+                    "" => invalid("empty segment"),
+                    "*" => Segment::Wildcard,
+                    key => Segment::Key(key.to_string()),
+                };
+                segments.push(segment);
+                rest = &plain[end..];
+            }
+            needs_dot = true;
+        }
+        Self(segments)
+    }
+}
+
+/// Parses the inside of a bracketed segment, `"key"`, an index or `*`, followed by `]`, returning
+/// the segment and the rest of the path.
+fn parse_bracketed(bracketed: &str) -> Option<(Segment, &str)> {
+    if bracketed.starts_with('"') {
+        let mut keys = serde_json::Deserializer::from_str(bracketed).into_iter::<String>();
+        let key = keys.next()?.ok()?;
+        let after = bracketed[keys.byte_offset()..].strip_prefix(']')?;
+        return Some((Segment::Key(key), after));
+    }
+    let (inner, after) = bracketed.split_once(']')?;
+    let segment = match inner {
+        "*" => Segment::Wildcard,
+        _ if !inner.is_empty() && inner.bytes().all(|b| b.is_ascii_digit()) => {
+            Segment::Key(inner.to_string())
+        }
+        _ => return None,
+    };
+    Some((segment, after))
+}
+
+impl Display for RelationPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "$")?;
+        for segment in &self.0 {
+            match segment {
+                Segment::Wildcard => write!(f, ".*")?,
+                Segment::Key(key) if !key.is_empty() && key != "*" && !key.contains(['.', '[']) => {
+                    write!(f, ".{}", key)?
+                }
+                Segment::Key(key) => write!(f, "[{}]", serde_json::to_string(key).unwrap())?,
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Order {
+    Before,
+    AtOrBefore,
+    Same,
+    AtOrAfter,
+    After,
+}
+
+impl Order {
+    fn holds(&self, datetime: &DateTime<FixedOffset>, other: &DateTime<FixedOffset>) -> bool {
+        match self {
+            Order::Before => datetime < other,
+            Order::AtOrBefore => datetime <= other,
+            Order::Same => datetime == other,
+            Order::AtOrAfter => datetime >= other,
+            Order::After => datetime > other,
+        }
+    }
+
+    fn describe(&self) -> &'static str {
+        match self {
+            Order::Before => "before",
+            Order::AtOrBefore => "at or before",
+            Order::Same => "the same instant as",
+            Order::AtOrAfter => "at or after",
+            Order::After => "after",
+        }
+    }
+}
+
+/// A datetime found at a path of the matched value.
+struct Found {
+    path: JsonPath,
+    raw: String,
+    datetime: DateTime<FixedOffset>,
+}
+
+impl Default for DateTimeRelations {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DateTimeRelations {
+    pub fn new() -> Self {
+        Self {
+            matcher: None,
+            relations: vec![],
+        }
+    }
+
+    /// Also requires the value to match `matcher`.
+    pub fn matching(mut self, matcher: impl JsonMatcher + 'static) -> Self {
+        self.matcher = Some(Box::new(matcher));
+        self
+    }
+
+    fn order(mut self, path: &str, other: &str, order: Order) -> Self {
+        self.relations.push(Relation::Order {
+            path: RelationPath::parse(path),
+            other: RelationPath::parse(other),
+            order,
+        });
+        self
+    }
+
+    /// Requires the datetimes at `path` to be strictly before those at `other`.
+    pub fn before(self, path: &str, other: &str) -> Self {
+        self.order(path, other, Order::Before)
+    }
+
+    /// Requires the datetimes at `path` to be at or before those at `other`.
+    pub fn at_or_before(self, path: &str, other: &str) -> Self {
+        self.order(path, other, Order::AtOrBefore)
+    }
+
+    /// Requires the datetimes at `path` to be the same instant as those at `other`, whatever their
+    /// offsets.
+    pub fn same_instant(self, path: &str, other: &str) -> Self {
+        self.order(path, other, Order::Same)
+    }
+
+    /// Requires the datetimes at `path` to be at or after those at `other`.
+    pub fn at_or_after(self, path: &str, other: &str) -> Self {
+        self.order(path, other, Order::AtOrAfter)
+    }
+
+    /// Requires the datetimes at `path` to be strictly after those at `other`.
+    pub fn after(self, path: &str, other: &str) -> Self {
+        self.order(path, other, Order::After)
+    }
+
+    /// Requires the datetimes at `to` to be exactly `gap` after those at `from`.
+    pub fn gap(self, from: &str, to: &str, gap: Duration) -> Self {
+        self.gap_between(from, to, gap, gap)
+    }
+
+    /// Requires the datetimes at `to` to be between `min` and `max` after those at `from`,
+    /// inclusive.
+    pub fn gap_between(mut self, from: &str, to: &str, min: Duration, max: Duration) -> Self {
+        self.relations.push(Relation::Gap {
+            from: RelationPath::parse(from),
+            to: RelationPath::parse(to),
+            min,
+            max,
+        });
+        self
+    }
+
+    /// Requires the datetimes at `path`, which would usually contain a `*`, to be strictly
+    /// increasing in document order.
+    pub fn increasing(mut self, path: &str) -> Self {
+        self.relations.push(Relation::Sequence {
+            path: RelationPath::parse(path),
+            strict: true,
+        });
+        self
+    }
+
+    /// Requires the datetimes at `path` to never decrease in document order.
+    pub fn non_decreasing(mut self, path: &str) -> Self {
+        self.relations.push(Relation::Sequence {
+            path: RelationPath::parse(path),
+            strict: false,
+        });
+        self
+    }
+
+    fn check(&self, value: &Value, relation: &Relation, errors: &mut Vec<JsonMatcherError>) {
+        match relation {
+            Relation::Order { path, other, order } => {
+                let found = find(value, path, errors);
+                let others = find(value, other, errors);
+                for datetime in &found {
+                    for other in &others {
+                        if !order.holds(&datetime.datetime, &other.datetime) {
+                            errors.push(JsonMatcherError {
+                                path: datetime.path.clone(),
+                                message: format!(
+                                    "Datetime {} is not {} {} ({})",
+                                    datetime.raw,
+                                    order.describe(),
+                                    other.path,
+                                    other.raw
+                                ),
+                            });
+                        }
+                    }
+                }
+            }
+            Relation::Gap { from, to, min, max } => {
+                let froms = find(value, from, errors);
+                let tos = find(value, to, errors);
+                for to in &tos {
+                    for from in &froms {
+                        let gap = to.datetime - from.datetime;
+                        if gap < *min || gap > *max {
+                            let expected = if min == max {
+                                min.to_string()
+                            } else {
+                                format!("between {} and {}", min, max)
+                            };
+                            errors.push(JsonMatcherError {
+                                path: to.path.clone(),
+                                message: format!(
+                                    "Gap from {} is {}, expected {}",
+                                    from.path, gap, expected
+                                ),
+                            });
+                        }
+                    }
+                }
+            }
+            Relation::Sequence { path, strict } => {
+                let found = find(value, path, errors);
+                for pair in found.windows(2) {
+                    let (previous, datetime) = (&pair[0], &pair[1]);
+                    let (order, message) = if *strict {
+                        (Order::After, "not after")
+                    } else {
+                        (Order::AtOrAfter, "before")
+                    };
+                    if !order.holds(&datetime.datetime, &previous.datetime) {
+                        errors.push(JsonMatcherError {
+                            path: datetime.path.clone(),
+                            message: format!(
+                                "Datetime {} is {} previous {} ({})",
+                                datetime.raw, message, previous.path, previous.raw
+                            ),
+                        });
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl JsonMatcher for DateTimeRelations {
+    fn json_matches(&self, value: &Value) -> Vec<JsonMatcherError> {
+        let mut errors = match &self.matcher {
+            Some(matcher) => matcher.json_matches(value),
+            None => vec![],
+        };
+        let mut relation_errors = vec![];
+        for relation in &self.relations {
+            self.check(value, relation, &mut relation_errors);
+        }
+        // a path used by several relations reports a missing or malformed datetime only once
+        for error in relation_errors {
+            if !errors.contains(&error) {
+                errors.push(error);
+            }
+        }
+        errors
+    }
+}
+
+/// Resolves a path relative to `value`, in which a wildcard stands for every element of an array
+/// or object.
+fn resolve<'a>(value: &'a Value, path: &RelationPath) -> Vec<(Vec<JsonPathElement>, &'a Value)> {
+    let mut resolved = vec![(vec![JsonPathElement::Root], value)];
+    for segment in &path.0 {
+        resolved = resolved
+            .into_iter()
+            .flat_map(|(path, value)| {
+                let children: Vec<(JsonPathElement, &Value)> = match (segment, value) {
+                    (Segment::Wildcard, Value::Array(elements)) => elements
+                        .iter()
+                        .enumerate()
+                        .map(|(i, element)| (JsonPathElement::Index(i), element))
+                        .collect(),
+                    (Segment::Wildcard, Value::Object(map)) => map
+                        .iter()
+                        .map(|(key, element)| (JsonPathElement::Key(key.clone()), element))
+                        .collect(),
+                    (Segment::Key(key), Value::Array(elements)) => key
+                        .parse::<usize>()
+                        .ok()
+                        .and_then(|i| Some((JsonPathElement::Index(i), elements.get(i)?)))
+                        .into_iter()
+                        .collect(),
+                    (Segment::Key(key), Value::Object(map)) => map
+                        .get(key)
+                        .map(|element| (JsonPathElement::Key(key.clone()), element))
+                        .into_iter()
+                        .collect(),
+                    _ => vec![],
+                };
+                children.into_iter().map(move |(element, child)| {
+                    let mut path = path.clone();
+                    path.push(element);
+                    (path, child)
+                })
+            })
+            .collect();
+    }
+    resolved
+}
+
+/// The datetimes at a path, pushing an error for each value that is not a datetime, or if the
+/// path matches no values.
+fn find(value: &Value, path: &RelationPath, errors: &mut Vec<JsonMatcherError>) -> Vec<Found> {
+    let resolved = resolve(value, path);
+    if resolved.is_empty() {
+        errors.push(JsonMatcherError::at_root(format!(
+            "No datetime at {}",
+            path
+        )));
+    }
+    resolved
+        .into_iter()
+        .filter_map(|(path, value)| {
+            let path = JsonPath::from(path);
+            let Value::String(raw) = value else {
+                errors.push(JsonMatcherError {
+                    path,
+                    message: "Datetime value needs to be a string".to_string(),
+                });
+                return None;
+            };
+            match parse_datetime_from_string(raw, None) {
+                Ok(datetime) => Some(Found {
+                    path,
+                    raw: raw.clone(),
+                    datetime,
+                }),
+                Err(err) => {
+                    errors.push(JsonMatcherError {
+                        path,
+                        message: format!("Could not parse string as rfc3339 datetime: {}", err),
+                    });
+                    None
+                }
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test::catch_string_panic;
+    use crate::{assert_jm, create_json_matcher, AnyMatcher};
+    use serde_json::json;
+
+    use super::*;
+
+    fn error_at(path: Vec<JsonPathElement>, message: &str) -> JsonMatcherError {
+        JsonMatcherError {
+            path: JsonPath::from(path),
+            message: message.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_order_relations() {
+        let value = json!({
+            "created_at": "2024-01-05T10:00:00Z",
+            "updated_at": "2024-01-05T12:00:00+02:00",
+            "deleted_at": "2024-01-05T09:00:00Z"
+        });
+        assert_jm!(
+            value,
+            DateTimeRelations::new()
+                .at_or_after("updated_at", "created_at")
+                .same_instant("$.updated_at", "created_at")
+                .before("deleted_at", "created_at")
+        );
+        assert_eq!(
+            DateTimeRelations::new()
+                .after("updated_at", "created_at")
+                .at_or_after("deleted_at", "created_at")
+                .json_matches(&value),
+            vec![
+                error_at(
+                    vec![JsonPathElement::Root, JsonPathElement::Key("updated_at".to_string())],
+                    "Datetime 2024-01-05T12:00:00+02:00 is not after $.created_at (2024-01-05T10:00:00Z)"
+                ),
+                error_at(
+                    vec![JsonPathElement::Root, JsonPathElement::Key("deleted_at".to_string())],
+                    "Datetime 2024-01-05T09:00:00Z is not at or after $.created_at (2024-01-05T10:00:00Z)"
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_gap_relations() {
+        let value = json!({
+            "issued_at": "2024-01-05T10:00:00Z",
+            "expires_at": "2024-01-05T10:30:00Z"
+        });
+        assert_jm!(
+            value,
+            DateTimeRelations::new()
+                .gap("issued_at", "expires_at", Duration::minutes(30))
+                .gap_between(
+                    "issued_at",
+                    "expires_at",
+                    Duration::minutes(1),
+                    Duration::hours(1)
+                )
+        );
+        assert_eq!(
+            DateTimeRelations::new()
+                .gap("issued_at", "expires_at", Duration::hours(1))
+                .gap_between(
+                    "expires_at",
+                    "issued_at",
+                    Duration::zero(),
+                    Duration::hours(1)
+                )
+                .json_matches(&value),
+            vec![
+                error_at(
+                    vec![
+                        JsonPathElement::Root,
+                        JsonPathElement::Key("expires_at".to_string())
+                    ],
+                    "Gap from $.issued_at is PT1800S, expected PT3600S"
+                ),
+                error_at(
+                    vec![
+                        JsonPathElement::Root,
+                        JsonPathElement::Key("issued_at".to_string())
+                    ],
+                    "Gap from $.expires_at is -PT1800S, expected between P0D and PT3600S"
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_sequence_relations() {
+        let value = json!({
+            "events": [
+                { "at": "2024-01-05T10:00:00Z" },
+                { "at": "2024-01-05T10:05:00Z" },
+                { "at": "2024-01-05T10:05:00Z" },
+                { "at": "2024-01-05T10:01:00Z" }
+            ]
+        });
+        let events_at = |i| {
+            vec![
+                JsonPathElement::Root,
+                JsonPathElement::Key("events".to_string()),
+                JsonPathElement::Index(i),
+                JsonPathElement::Key("at".to_string()),
+            ]
+        };
+        assert_eq!(
+            DateTimeRelations::new()
+                .increasing("events.*.at")
+                .json_matches(&value),
+            vec![
+                error_at(
+                    events_at(2),
+                    "Datetime 2024-01-05T10:05:00Z is not after previous $.events.1.at (2024-01-05T10:05:00Z)"
+                ),
+                error_at(
+                    events_at(3),
+                    "Datetime 2024-01-05T10:01:00Z is not after previous $.events.2.at (2024-01-05T10:05:00Z)"
+                ),
+            ]
+        );
+        assert_eq!(
+            DateTimeRelations::new()
+                .non_decreasing("events.*.at")
+                .json_matches(&value),
+            vec![error_at(
+                events_at(3),
+                "Datetime 2024-01-05T10:01:00Z is before previous $.events.2.at (2024-01-05T10:05:00Z)"
+            )]
+        );
+        assert_jm!(
+            value,
+            DateTimeRelations::new().non_decreasing("events.0.at")
+        );
+        assert_eq!(
+            DateTimeRelations::new()
+                .increasing("missing.*")
+                .increasing("events[4].at")
+                .json_matches(&value),
+            vec![
+                JsonMatcherError::at_root("No datetime at $.missing.*"),
+                JsonMatcherError::at_root("No datetime at $.events.4.at"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_bracketed_paths() {
+        let value = json!({
+            "created.at": "2024-01-05T10:00:00Z",
+            "history": [{ "[at]": "2024-01-05T11:00:00Z" }]
+        });
+        assert_jm!(
+            value,
+            DateTimeRelations::new()
+                .after(r#"history[*]["[at]"]"#, r#"$["created.at"]"#)
+                .before(r#"["created.at"]"#, r#"$.history[0]["[at]"]"#)
+        );
+        assert_eq!(
+            DateTimeRelations::new()
+                .after("created.at", r#"$["created\u002eat"]"#)
+                .json_matches(&value),
+            vec![JsonMatcherError::at_root("No datetime at $.created.at")]
+        );
+        assert_eq!(
+            DateTimeRelations::new()
+                .after(r#"$["updated.at"]"#, "history.*.at")
+                .json_matches(&value),
+            vec![
+                JsonMatcherError::at_root(r#"No datetime at $["updated.at"]"#),
+                JsonMatcherError::at_root("No datetime at $.history.*.at"),
+            ]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid datetime path \"created[at]\": malformed brackets")]
+    fn test_malformed_path() {
+        DateTimeRelations::new().increasing("created[at]");
+    }
+
+    #[test]
+    fn test_relation_value_errors() {
+        let value = json!({ "created_at": "yesterday", "updated_at": 5 });
+        assert_eq!(
+            catch_string_panic(|| assert_jm!(
+                value,
+                DateTimeRelations::new()
+                    .after("updated_at", "created_at")
+                    .after("removed_at", "created_at")
+                    .matching(create_json_matcher!({
+                        "created_at": AnyMatcher::new(),
+                        "updated_at": AnyMatcher::new(),
+                        "removed_at": AnyMatcher::new()
+                    }))
+            )),
+            r#"
+Json matcher failed:
+  - $: Object is missing keys: removed_at
+  - $.updated_at: Datetime value needs to be a string
+  - $.created_at: Could not parse string as rfc3339 datetime: Value cannot be parsed as an RFC 3339 timestamp: input contains invalid characters
+  - $: No datetime at $.removed_at
+
+Actual:
+{
+  "created_at": "yesterday",
+  "updated_at": 5
+}"#
+        );
+    }
+}
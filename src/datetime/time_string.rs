@@ -0,0 +1,125 @@
+use crate::{JsonMatcher, JsonMatcherError};
+use chrono::NaiveTime;
+use serde_json::Value;
+
+use super::{bound_builders, has_shape, Bounds};
+
+/// Matches strings holding a time of day in `HH:MM:SS` form, with optional fractional seconds,
+/// optionally within bounds.
+///
+/// ```
+/// use chrono::NaiveTime;
+/// use json_matcher::{assert_jm, datetime::TimeStringMatcher};
+/// use serde_json::json;
+///
+/// let opening = NaiveTime::from_hms_opt(9, 0, 0).unwrap();
+/// let closing = NaiveTime::from_hms_opt(17, 30, 0).unwrap();
+/// assert_jm!(json!({ "last_order": "17:15:00.250" }), {
+///     "last_order": TimeStringMatcher::new().between(opening, closing)
+/// });
+/// ```
+pub struct TimeStringMatcher {
+    bounds: Bounds<NaiveTime>,
+}
+
+impl Default for TimeStringMatcher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TimeStringMatcher {
+    /// Matches any time of day.
+    pub fn new() -> Self {
+        Self {
+            bounds: Bounds::new(),
+        }
+    }
+}
+
+bound_builders!(TimeStringMatcher, NaiveTime, "time");
+
+impl JsonMatcher for TimeStringMatcher {
+    fn json_matches(&self, value: &Value) -> Vec<JsonMatcherError> {
+        let Value::String(as_str) = value else {
+            return vec![JsonMatcherError::at_root("Time value needs to be a string")];
+        };
+        if !as_str
+            .get(..8)
+            .is_some_and(|hms| has_shape(hms, "99:99:99"))
+        {
+            return vec![JsonMatcherError::at_root(
+                "Could not parse string as HH:MM:SS time",
+            )];
+        }
+        let time = match NaiveTime::parse_from_str(as_str, "%H:%M:%S%.f") {
+            Ok(time) => time,
+            Err(err) => {
+                return vec![JsonMatcherError::at_root(format!(
+                    "Could not parse string as HH:MM:SS time: {}",
+                    err
+                ))];
+            }
+        };
+        if let Some(error) = self.bounds.check(time, "Time") {
+            return vec![JsonMatcherError::at_root(error)];
+        }
+        vec![]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::assert_jm;
+    use chrono::Duration;
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_time_string_matcher() {
+        let t = NaiveTime::from_hms_opt(23, 59, 0).unwrap();
+        assert_jm!(json!("00:00:00"), TimeStringMatcher::new());
+        assert_jm!(json!("23:59:30.125"), TimeStringMatcher::new().after(t));
+        // the window is cut off at midnight rather than wrapping around
+        let matcher = TimeStringMatcher::new().within(Duration::minutes(5)).of(t);
+        assert_jm!(json!("23:59:59.999"), matcher);
+        assert_eq!(matcher.lower_bound(), NaiveTime::from_hms_opt(23, 54, 0));
+        assert_eq!(
+            matcher.json_matches(&json!("00:01:00")),
+            vec![JsonMatcherError::at_root(
+                "Time is before lower bound of 23:54:00"
+            )]
+        );
+        assert_eq!(
+            TimeStringMatcher::new().json_matches(&json!(null)),
+            vec![JsonMatcherError::at_root("Time value needs to be a string")]
+        );
+        assert_eq!(
+            TimeStringMatcher::new().json_matches(&json!("9:00:00")),
+            vec![JsonMatcherError::at_root(
+                "Could not parse string as HH:MM:SS time"
+            )]
+        );
+        assert_eq!(
+            TimeStringMatcher::new().json_matches(&json!("24:00:00")),
+            vec![JsonMatcherError::at_root(
+                "Could not parse string as HH:MM:SS time: input is out of range"
+            )]
+        );
+        assert_eq!(
+            TimeStringMatcher::new().json_matches(&json!("12:00:00Z")),
+            vec![JsonMatcherError::at_root(
+                "Could not parse string as HH:MM:SS time: trailing input"
+            )]
+        );
+        assert_eq!(
+            TimeStringMatcher::new()
+                .at_or_before(t)
+                .json_matches(&json!("23:59:00.5")),
+            vec![JsonMatcherError::at_root(
+                "Time is after upper bound of 23:59:00"
+            )]
+        );
+    }
+}
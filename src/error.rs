@@ -1,10 +1,14 @@
 use std::fmt::Display;
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum JsonPathElement {
     Root,
     Index(usize),
     Key(String),
+    /// A step that decodes the value at the preceding path, such as the payload of a JWT. It is
+    /// displayed as `<payload>` attached to the preceding element, as in `$.token<payload>.sub`.
+    Decoded(String),
 }
 
 impl Display for JsonPathElement {
@@ -13,6 +17,7 @@ impl Display for JsonPathElement {
             JsonPathElement::Root => "$".to_string(),
             JsonPathElement::Index(index) => format!("{}", index),
             JsonPathElement::Key(key) => key.to_string(),
+            JsonPathElement::Decoded(step) => format!("<{}>", step),
         };
         write!(f, "{}", s)
     }
@@ -23,15 +28,13 @@ pub struct JsonPath(Vec<JsonPathElement>);
 
 impl Display for JsonPath {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(
-            f,
-            "{}",
-            self.0
-                .iter()
-                .map(|e| format!("{}", e))
-                .collect::<Vec<String>>()
-                .join(".")
-        )
+        for (i, element) in self.0.iter().enumerate() {
+            if i > 0 && !matches!(element, JsonPathElement::Decoded(_)) {
+                write!(f, ".")?;
+            }
+            write!(f, "{}", element)?;
+        }
+        Ok(())
     }
 }
 
@@ -42,6 +45,10 @@ impl From<Vec<JsonPathElement>> for JsonPath {
 }
 
 impl JsonPath {
+    pub fn elements(&self) -> &[JsonPathElement] {
+        &self.0
+    }
+
     pub fn extend<T: Into<JsonPath>>(mut self, elements: T) -> Self {
         let mut elements = Into::<JsonPath>::into(elements).0;
         if elements.first() == Some(&JsonPathElement::Root) {
@@ -0,0 +1,287 @@
+use std::marker::PhantomData;
+
+use serde_json::Value;
+
+use crate::matchers::{is_float, Decimal};
+use crate::{JsonMatcher, JsonMatcherError, NumericPolicy};
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// A primitive integer type that [`FitsMatcher`] can check values against.
+///
+/// This trait is sealed and implemented for every primitive integer type.
+pub trait FitsInteger: private::Sealed + Sized {
+    #[doc(hidden)]
+    const NAME: &'static str;
+    #[doc(hidden)]
+    fn from_str_radix(s: &str, radix: u32) -> Option<Self>;
+}
+
+macro_rules! impl_fits_integer {
+    ($($t:ty),*) => {
+        $(
+            impl private::Sealed for $t {}
+
+            impl FitsInteger for $t {
+                const NAME: &'static str = stringify!($t);
+
+                fn from_str_radix(s: &str, radix: u32) -> Option<Self> {
+                    <$t>::from_str_radix(s, radix).ok()
+                }
+            }
+        )*
+    };
+}
+
+impl_fits_integer!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+/// Matches integers that fit in the primitive integer type `T`, e.g. `FitsMatcher::<u16>::new()`
+/// for port numbers.
+///
+/// With [`allow_strings`](FitsMatcher::allow_strings) the matcher instead expects a string holding
+/// the integer, as JavaScript clients send 64 bit IDs. Strings must be in canonical decimal form
+/// unless [`allow_hex`](FitsMatcher::allow_hex),
+/// [`allow_leading_zeros`](FitsMatcher::allow_leading_zeros) or
+/// [`allow_plus_sign`](FitsMatcher::allow_plus_sign) are set.
+pub struct FitsMatcher<T: FitsInteger> {
+    allow_strings: bool,
+    allow_hex: bool,
+    allow_leading_zeros: bool,
+    allow_plus_sign: bool,
+    policy: Option<NumericPolicy>,
+    _type: PhantomData<fn() -> T>,
+}
+
+impl<T: FitsInteger> Default for FitsMatcher<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: FitsInteger> FitsMatcher<T> {
+    pub fn new() -> Self {
+        Self {
+            allow_strings: false,
+            allow_hex: false,
+            allow_leading_zeros: false,
+            allow_plus_sign: false,
+            policy: None,
+            _type: PhantomData,
+        }
+    }
+
+    pub fn new_allow_strings() -> Self {
+        Self::new().allow_strings()
+    }
+
+    /// Expects a string holding the integer instead of a number.
+    pub fn allow_strings(mut self) -> Self {
+        self.allow_strings = true;
+        self
+    }
+
+    /// Also accepts strings holding the integer in hexadecimal with a `0x` prefix, e.g. `"0x1F"`.
+    pub fn allow_hex(mut self) -> Self {
+        self.allow_hex = true;
+        self
+    }
+
+    /// Also accepts strings with leading zeros, e.g. `"0042"`.
+    pub fn allow_leading_zeros(mut self) -> Self {
+        self.allow_leading_zeros = true;
+        self
+    }
+
+    /// Also accepts strings with a leading `+`, e.g. `"+42"`.
+    pub fn allow_plus_sign(mut self) -> Self {
+        self.allow_plus_sign = true;
+        self
+    }
+
+    /// Uses this policy instead of [`NumericPolicy::current`] to decide whether floats without a
+    /// fractional part fit.
+    pub fn policy(mut self, policy: NumericPolicy) -> Self {
+        self.policy = Some(policy);
+        self
+    }
+
+    fn parse(&self, s: &str) -> Option<T> {
+        let (sign, unsigned) = match (s.strip_prefix('-'), s.strip_prefix('+')) {
+            (Some(rest), _) => ("-", rest),
+            (_, Some(rest)) if self.allow_plus_sign => ("", rest),
+            _ => ("", s),
+        };
+        let (digits, radix) = match unsigned
+            .strip_prefix("0x")
+            .or_else(|| unsigned.strip_prefix("0X"))
+        {
+            Some(hex) if self.allow_hex => (hex, 16),
+            _ => (unsigned, 10),
+        };
+        if digits.is_empty() || !digits.chars().all(|c| c.is_digit(radix)) {
+            return None;
+        }
+        if !self.allow_leading_zeros && radix == 10 && digits.len() > 1 && digits.starts_with('0') {
+            return None;
+        }
+        T::from_str_radix(&format!("{}{}", sign, digits), radix)
+    }
+}
+
+impl<T: FitsInteger> JsonMatcher for FitsMatcher<T> {
+    fn json_matches(&self, value: &Value) -> Vec<JsonMatcherError> {
+        if self.allow_strings {
+            return match value.as_str() {
+                Some(s) if self.parse(s).is_some() => vec![],
+                Some(_) => vec![JsonMatcherError::at_root(format!(
+                    "Expected number fitting {}",
+                    T::NAME
+                ))],
+                None => vec![JsonMatcherError::at_root(format!(
+                    "Expected string fitting {}",
+                    T::NAME
+                ))],
+            };
+        }
+        let fits = match value {
+            Value::Number(n) => {
+                let actual = match Decimal::from_number(n) {
+                    Ok(actual) => actual,
+                    Err(error) => return vec![error],
+                };
+                let integral = match self.policy.unwrap_or_else(NumericPolicy::current) {
+                    NumericPolicy::Strict => !is_float(n),
+                    _ => actual.is_integer(),
+                };
+                if !integral {
+                    return vec![JsonMatcherError::at_root(format!(
+                        "Expected number fitting {}",
+                        T::NAME
+                    ))];
+                }
+                T::from_str_radix(&actual.to_string(), 10).is_some()
+            }
+            _ => {
+                return vec![JsonMatcherError::at_root(format!(
+                    "Expected number fitting {}",
+                    T::NAME
+                ))];
+            }
+        };
+        match fits {
+            true => vec![],
+            false => vec![JsonMatcherError::at_root(format!(
+                "Integer out of bounds for {}",
+                T::NAME
+            ))],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::assert_jm;
+
+    use super::*;
+
+    #[test]
+    fn test_fits_matcher_numbers() {
+        assert_jm!(json!(255), FitsMatcher::<u8>::new());
+        assert_jm!(json!(-128), FitsMatcher::<i8>::new());
+        assert_jm!(json!(u64::MAX), FitsMatcher::<u64>::new());
+        assert_jm!(json!(u64::MAX), FitsMatcher::<u128>::new());
+        assert_jm!(json!(i64::MIN), FitsMatcher::<i128>::new());
+        assert_eq!(
+            FitsMatcher::<u8>::new().json_matches(&json!(256)),
+            vec![JsonMatcherError::at_root("Integer out of bounds for u8")]
+        );
+        assert_eq!(
+            FitsMatcher::<i64>::new().json_matches(&json!(u64::MAX)),
+            vec![JsonMatcherError::at_root("Integer out of bounds for i64")]
+        );
+        assert_eq!(
+            FitsMatcher::<u64>::new().json_matches(&json!(-1)),
+            vec![JsonMatcherError::at_root("Integer out of bounds for u64")]
+        );
+        assert_eq!(
+            FitsMatcher::<i32>::new().json_matches(&json!(1.5)),
+            vec![JsonMatcherError::at_root("Expected number fitting i32")]
+        );
+        assert_eq!(
+            FitsMatcher::<i32>::new().json_matches(&json!("1")),
+            vec![JsonMatcherError::at_root("Expected number fitting i32")]
+        );
+        let matcher = FitsMatcher::<u8>::new().policy(NumericPolicy::IntegralFloatsAllowed);
+        assert_jm!(json!(255.0), matcher);
+        assert_eq!(
+            matcher.json_matches(&json!(256.0)),
+            vec![JsonMatcherError::at_root("Integer out of bounds for u8")]
+        );
+        assert_eq!(
+            matcher.json_matches(&json!(1.5)),
+            vec![JsonMatcherError::at_root("Expected number fitting u8")]
+        );
+    }
+
+    #[test]
+    fn test_fits_matcher_strings() {
+        let matcher = FitsMatcher::<i64>::new_allow_strings();
+        assert_jm!(json!("9223372036854775807"), matcher);
+        assert_jm!(json!("-9223372036854775808"), matcher);
+        assert_jm!(json!("0"), matcher);
+        for invalid in [
+            "9223372036854775808",
+            "",
+            "-",
+            "+1",
+            " 1",
+            "0042",
+            "0x1F",
+            "1.0",
+        ] {
+            assert_eq!(
+                matcher.json_matches(&json!(invalid)),
+                vec![JsonMatcherError::at_root("Expected number fitting i64")]
+            );
+        }
+        assert_eq!(
+            matcher.json_matches(&json!(42)),
+            vec![JsonMatcherError::at_root("Expected string fitting i64")]
+        );
+        assert_jm!(
+            json!("18446744073709551615"),
+            FitsMatcher::<u64>::new_allow_strings()
+        );
+
+        let matcher = FitsMatcher::<u16>::new().allow_strings().allow_hex();
+        assert_jm!(json!("0xFFFF"), matcher);
+        assert_jm!(json!("0x1f"), matcher);
+        assert_eq!(
+            matcher.json_matches(&json!("0x10000")),
+            vec![JsonMatcherError::at_root("Expected number fitting u16")]
+        );
+        assert_eq!(
+            matcher.json_matches(&json!("0x")),
+            vec![JsonMatcherError::at_root("Expected number fitting u16")]
+        );
+
+        let matcher = FitsMatcher::<u16>::new()
+            .allow_strings()
+            .allow_leading_zeros();
+        assert_jm!(json!("0042"), matcher);
+        assert_jm!(json!("00000"), matcher);
+
+        let matcher = FitsMatcher::<i8>::new_allow_strings().allow_plus_sign();
+        assert_jm!(json!("+127"), matcher);
+        for invalid in ["+128", "+-1", "-+1", "+"] {
+            assert_eq!(
+                matcher.json_matches(&json!(invalid)),
+                vec![JsonMatcherError::at_root("Expected number fitting i8")]
+            );
+        }
+    }
+}
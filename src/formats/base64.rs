@@ -0,0 +1,223 @@
+use serde_json::Value;
+
+use crate::{JsonMatcher, JsonMatcherError};
+
+/// Whether base64 strings must end with `=` padding to a multiple of four characters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Base64Padding {
+    Required,
+    Optional,
+    Forbidden,
+}
+
+/// The alphabet and padding rules of a base64 variant.
+#[derive(Clone, Copy)]
+pub(crate) struct Base64Format {
+    pub(crate) url_safe: bool,
+    pub(crate) padding: Base64Padding,
+}
+
+impl Base64Format {
+    /// The name of the variant, as used in error messages.
+    pub(crate) fn name(self) -> &'static str {
+        if self.url_safe {
+            "base64url"
+        } else {
+            "base64"
+        }
+    }
+
+    /// The capitalized name of the variant, for the start of error messages.
+    pub(crate) fn label(self) -> &'static str {
+        if self.url_safe {
+            "Base64url"
+        } else {
+            "Base64"
+        }
+    }
+
+    fn sextet(self, c: u8) -> Option<u8> {
+        match c {
+            b'A'..=b'Z' => Some(c - b'A'),
+            b'a'..=b'z' => Some(c - b'a' + 26),
+            b'0'..=b'9' => Some(c - b'0' + 52),
+            b'+' if !self.url_safe => Some(62),
+            b'/' if !self.url_safe => Some(63),
+            b'-' if self.url_safe => Some(62),
+            b'_' if self.url_safe => Some(63),
+            _ => None,
+        }
+    }
+
+    /// Decodes `encoded`, returning what is wrong with it if it is not valid in this format.
+    pub(crate) fn decode(self, encoded: &str) -> Result<Vec<u8>, String> {
+        let data = encoded.trim_end_matches('=');
+        let padding = encoded.len() - data.len();
+        if let Some((i, c)) = data
+            .char_indices()
+            .find(|(_, c)| !c.is_ascii() || self.sextet(*c as u8).is_none())
+        {
+            return Err(format!("has invalid character {:?} at index {}", c, i));
+        }
+        if data.len() % 4 == 1 {
+            return Err(format!("has invalid length {}", data.len()));
+        }
+        let expected_padding = (4 - data.len() % 4) % 4;
+        match self.padding {
+            _ if padding > 0 && padding != expected_padding => {
+                return Err("has incorrect padding".to_string())
+            }
+            Base64Padding::Required if padding < expected_padding => {
+                return Err("is missing padding".to_string())
+            }
+            Base64Padding::Forbidden if padding > 0 => return Err("has padding".to_string()),
+            _ => {}
+        }
+        let mut bytes = Vec::with_capacity(data.len() * 3 / 4);
+        let mut buffer = 0_u32;
+        let mut bits = 0;
+        for c in data.bytes() {
+            buffer = (buffer << 6) | u32::from(self.sextet(c).unwrap_or_default());
+            bits += 6;
+            if bits >= 8 {
+                bits -= 8;
+                bytes.push((buffer >> bits) as u8);
+            }
+        }
+        // the bits left over in a final partial group must be zero, so each value has one encoding
+        if buffer & ((1 << bits) - 1) != 0 {
+            return Err("has non-zero trailing bits".to_string());
+        }
+        Ok(bytes)
+    }
+}
+
+/// Matches strings holding base64-encoded data (RFC 4648).
+///
+/// [`Base64Matcher::new`] expects the standard alphabet with `+` and `/` and requires padding;
+/// [`Base64Matcher::url_safe`] expects the URL-safe alphabet with `-` and `_`, with or without
+/// padding. Either can be changed with [`padding`](Base64Matcher::padding).
+pub struct Base64Matcher {
+    format: Base64Format,
+}
+
+impl Default for Base64Matcher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Base64Matcher {
+    pub fn new() -> Self {
+        Self {
+            format: Base64Format {
+                url_safe: false,
+                padding: Base64Padding::Required,
+            },
+        }
+    }
+
+    pub fn url_safe() -> Self {
+        Self {
+            format: Base64Format {
+                url_safe: true,
+                padding: Base64Padding::Optional,
+            },
+        }
+    }
+
+    pub fn padding(mut self, padding: Base64Padding) -> Self {
+        self.format.padding = padding;
+        self
+    }
+}
+
+impl JsonMatcher for Base64Matcher {
+    fn json_matches(&self, value: &Value) -> Vec<JsonMatcherError> {
+        let name = self.format.name();
+        let Some(encoded) = value.as_str() else {
+            return vec![JsonMatcherError::at_root(format!(
+                "Expected string for {}",
+                name
+            ))];
+        };
+        match self.format.decode(encoded) {
+            Ok(_) => vec![],
+            Err(problem) => vec![JsonMatcherError::at_root(format!(
+                "{} string \"{}\" {}",
+                self.format.label(),
+                encoded,
+                problem
+            ))],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::assert_jm;
+
+    use super::*;
+
+    #[test]
+    fn test_base64_decode() {
+        let standard = Base64Matcher::new().format;
+        for (encoded, decoded) in [
+            ("", ""),
+            ("Zg==", "f"),
+            ("Zm8=", "fo"),
+            ("Zm9v", "foo"),
+            ("Zm9vYmFy", "foobar"),
+        ] {
+            assert_eq!(standard.decode(encoded).unwrap(), decoded.as_bytes());
+        }
+        let url_safe = Base64Matcher::url_safe().format;
+        assert_eq!(url_safe.decode("-_8").unwrap(), vec![0xfb, 0xff]);
+        assert_eq!(standard.decode("+/8=").unwrap(), vec![0xfb, 0xff]);
+    }
+
+    #[test]
+    fn test_base64_matcher() {
+        assert_jm!(json!("aGVsbG8gd29ybGQ="), Base64Matcher::new());
+        assert_jm!(json!("aGVsbG8gd29ybGQ"), Base64Matcher::url_safe());
+        assert_jm!(json!("aGVsbG8gd29ybGQ="), Base64Matcher::url_safe());
+        let cases = [
+            (
+                "aGVsbG8gd29ybGQ",
+                "Base64 string \"aGVsbG8gd29ybGQ\" is missing padding",
+            ),
+            (
+                "aGVsbG8=gd29y",
+                "Base64 string \"aGVsbG8=gd29y\" has invalid character '=' at index 7",
+            ),
+            (
+                "-_8=",
+                "Base64 string \"-_8=\" has invalid character '-' at index 0",
+            ),
+            ("Zm9vY", "Base64 string \"Zm9vY\" has invalid length 5"),
+            ("Zg=", "Base64 string \"Zg=\" has incorrect padding"),
+            ("Zh==", "Base64 string \"Zh==\" has non-zero trailing bits"),
+            ("Zm9=", "Base64 string \"Zm9=\" has non-zero trailing bits"),
+        ];
+        for (encoded, message) in cases {
+            assert_eq!(
+                Base64Matcher::new().json_matches(&json!(encoded)),
+                vec![JsonMatcherError::at_root(message)]
+            );
+        }
+        assert_eq!(
+            Base64Matcher::url_safe()
+                .padding(Base64Padding::Forbidden)
+                .json_matches(&json!("Zg==")),
+            vec![JsonMatcherError::at_root(
+                "Base64url string \"Zg==\" has padding"
+            )]
+        );
+        assert_eq!(
+            Base64Matcher::new().json_matches(&json!(42)),
+            vec![JsonMatcherError::at_root("Expected string for base64")]
+        );
+    }
+}
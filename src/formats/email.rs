@@ -0,0 +1,197 @@
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+use serde_json::Value;
+
+use super::hostname::check_hostname;
+use crate::{JsonMatcher, JsonMatcherError};
+
+/// Matches strings holding an email address.
+///
+/// [`EmailMatcher::new`] accepts the addresses in practical use: a dot-separated local part of
+/// letters, digits and ``!#$%&'*+/=?^_`{|}~-``, and a domain with at least two labels.
+/// [`EmailMatcher::rfc5322`] also accepts the rarer forms of the simplified RFC 5322 `addr-spec`,
+/// namely quoted local parts (`"john doe"@example.com`), domain literals (`admin@[192.0.2.1]`) and
+/// single-label domains (`root@localhost`). Comments and folding whitespace are never accepted.
+pub struct EmailMatcher {
+    rfc5322: bool,
+}
+
+impl Default for EmailMatcher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EmailMatcher {
+    pub fn new() -> Self {
+        Self { rfc5322: false }
+    }
+
+    pub fn rfc5322() -> Self {
+        Self { rfc5322: true }
+    }
+
+    fn check(&self, email: &str) -> Result<(), String> {
+        if email.len() > 254 {
+            return Err(format!("has {} characters, more than 254", email.len()));
+        }
+        let Some((local, domain)) = email.rsplit_once('@') else {
+            return Err("has no @".to_string());
+        };
+        if local.is_empty() {
+            return Err("has an empty local part".to_string());
+        }
+        if local.len() > 64 {
+            return Err(format!(
+                "has a local part of {} characters, more than 64",
+                local.len()
+            ));
+        }
+        if self.rfc5322 && local.len() >= 2 && local.starts_with('"') && local.ends_with('"') {
+            check_quoted_local(&local[1..local.len() - 1])?;
+        } else {
+            check_dot_atom(local)?;
+        }
+        if domain.is_empty() {
+            return Err("has an empty domain".to_string());
+        }
+        if self.rfc5322 && domain.starts_with('[') && domain.ends_with(']') {
+            return check_domain_literal(&domain[1..domain.len() - 1]);
+        }
+        check_hostname(domain)
+            .map_err(|problem| format!("has domain \"{}\" that {}", domain, problem))?;
+        if !self.rfc5322 && !domain.contains('.') {
+            return Err(format!("has domain \"{}\" without a dot", domain));
+        }
+        Ok(())
+    }
+}
+
+fn is_atext(c: char) -> bool {
+    c.is_ascii_alphanumeric() || "!#$%&'*+/=?^_`{|}~-".contains(c)
+}
+
+fn check_dot_atom(local: &str) -> Result<(), String> {
+    if let Some(c) = local.chars().find(|c| !is_atext(*c) && *c != '.') {
+        return Err(format!("has invalid character {:?} in the local part", c));
+    }
+    if local.starts_with('.') || local.ends_with('.') || local.contains("..") {
+        return Err("has a local part with a misplaced dot".to_string());
+    }
+    Ok(())
+}
+
+fn check_quoted_local(quoted: &str) -> Result<(), String> {
+    let mut chars = quoted.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => match chars.next() {
+                Some(' '..='~') => {}
+                _ => return Err("has an invalid escape in the quoted local part".to_string()),
+            },
+            '"' => return Err("has an unescaped quote in the quoted local part".to_string()),
+            ' '..='~' => {}
+            c => {
+                return Err(format!(
+                    "has invalid character {:?} in the quoted local part",
+                    c
+                ))
+            }
+        }
+    }
+    Ok(())
+}
+
+fn check_domain_literal(literal: &str) -> Result<(), String> {
+    let valid = match literal.strip_prefix("IPv6:") {
+        Some(v6) => v6.parse::<Ipv6Addr>().is_ok(),
+        None => literal.parse::<Ipv4Addr>().is_ok(),
+    };
+    if valid {
+        Ok(())
+    } else {
+        Err(format!("has invalid domain literal \"[{}]\"", literal))
+    }
+}
+
+impl JsonMatcher for EmailMatcher {
+    fn json_matches(&self, value: &Value) -> Vec<JsonMatcherError> {
+        let Some(email) = value.as_str() else {
+            return vec![JsonMatcherError::at_root("Expected string for email")];
+        };
+        match self.check(email) {
+            Ok(()) => vec![],
+            Err(problem) => vec![JsonMatcherError::at_root(format!(
+                "Email \"{}\" {}",
+                email, problem
+            ))],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::assert_jm;
+
+    use super::*;
+
+    #[test]
+    fn test_email_matcher() {
+        for valid in [
+            "ada@example.com",
+            "first.last+tag@mail.example.co.uk",
+            "o'brien@example.ie",
+        ] {
+            assert_jm!(json!(valid), EmailMatcher::new());
+            assert_jm!(json!(valid), EmailMatcher::rfc5322());
+        }
+        let cases = [
+            ("example.com", "Email \"example.com\" has no @"),
+            ("@example.com", "Email \"@example.com\" has an empty local part"),
+            ("ada@", "Email \"ada@\" has an empty domain"),
+            (
+                "ada lovelace@example.com",
+                "Email \"ada lovelace@example.com\" has invalid character ' ' in the local part",
+            ),
+            (
+                "ada..l@example.com",
+                "Email \"ada..l@example.com\" has a local part with a misplaced dot",
+            ),
+            (
+                "ada@exa_mple.com",
+                "Email \"ada@exa_mple.com\" has domain \"exa_mple.com\" that has invalid character '_'",
+            ),
+            (
+                "root@localhost",
+                "Email \"root@localhost\" has domain \"localhost\" without a dot",
+            ),
+        ];
+        for (email, message) in cases {
+            assert_eq!(
+                EmailMatcher::new().json_matches(&json!(email)),
+                vec![JsonMatcherError::at_root(message)]
+            );
+        }
+        for valid in [
+            "root@localhost",
+            "\"john doe\"@example.com",
+            "\"a\\\"b\"@example.com",
+            "admin@[192.0.2.1]",
+            "admin@[IPv6:2001:db8::1]",
+        ] {
+            assert_jm!(json!(valid), EmailMatcher::rfc5322());
+        }
+        assert_eq!(
+            EmailMatcher::rfc5322().json_matches(&json!("admin@[300.0.0.1]")),
+            vec![JsonMatcherError::at_root(
+                "Email \"admin@[300.0.0.1]\" has invalid domain literal \"[300.0.0.1]\""
+            )]
+        );
+        assert_eq!(
+            EmailMatcher::new().json_matches(&json!(null)),
+            vec![JsonMatcherError::at_root("Expected string for email")]
+        );
+    }
+}
@@ -0,0 +1,137 @@
+use serde_json::Value;
+
+use crate::{JsonMatcher, JsonMatcherError};
+
+#[derive(Clone, Copy, PartialEq)]
+enum HexCase {
+    Any,
+    Lower,
+    Upper,
+}
+
+/// Matches non-empty strings of hexadecimal digits, such as hashes and hex-encoded bytes, e.g.
+/// `"9f86d081884c7d65"`. Digits may be in either case unless [`lowercase`](HexMatcher::lowercase)
+/// or [`uppercase`](HexMatcher::uppercase) is given.
+pub struct HexMatcher {
+    len: Option<usize>,
+    case: HexCase,
+}
+
+impl Default for HexMatcher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl HexMatcher {
+    pub fn new() -> Self {
+        Self {
+            len: None,
+            case: HexCase::Any,
+        }
+    }
+
+    /// Requires exactly `len` hex digits, e.g. 64 for a SHA-256 hash.
+    pub fn exact_len(mut self, len: usize) -> Self {
+        self.len = Some(len);
+        self
+    }
+
+    pub fn lowercase(mut self) -> Self {
+        self.case = HexCase::Lower;
+        self
+    }
+
+    pub fn uppercase(mut self) -> Self {
+        self.case = HexCase::Upper;
+        self
+    }
+
+    fn check(&self, hex: &str) -> Result<(), String> {
+        if hex.is_empty() {
+            return Err("is empty".to_string());
+        }
+        if let Some((i, c)) = hex.char_indices().find(|(_, c)| !c.is_ascii_hexdigit()) {
+            return Err(format!("has non-hex character {:?} at index {}", c, i));
+        }
+        match self.case {
+            HexCase::Lower if hex.bytes().any(|b| b.is_ascii_uppercase()) => {
+                return Err("is not lowercase".to_string())
+            }
+            HexCase::Upper if hex.bytes().any(|b| b.is_ascii_lowercase()) => {
+                return Err("is not uppercase".to_string())
+            }
+            _ => {}
+        }
+        if let Some(len) = self.len {
+            if hex.len() != len {
+                return Err(format!("has {} digits, expected {}", hex.len(), len));
+            }
+        }
+        Ok(())
+    }
+}
+
+impl JsonMatcher for HexMatcher {
+    fn json_matches(&self, value: &Value) -> Vec<JsonMatcherError> {
+        let Some(hex) = value.as_str() else {
+            return vec![JsonMatcherError::at_root("Expected string for hex")];
+        };
+        match self.check(hex) {
+            Ok(()) => vec![],
+            Err(problem) => vec![JsonMatcherError::at_root(format!(
+                "Hex string \"{}\" {}",
+                hex, problem
+            ))],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::assert_jm;
+
+    use super::*;
+
+    #[test]
+    fn test_hex_matcher() {
+        assert_jm!(json!("9f86D081"), HexMatcher::new());
+        assert_jm!(json!("abc1234"), HexMatcher::new().exact_len(7).lowercase());
+        assert_jm!(json!("DEADBEEF"), HexMatcher::new().uppercase());
+        let cases = [
+            (HexMatcher::new(), "", "Hex string \"\" is empty"),
+            (
+                HexMatcher::new(),
+                "0x1f",
+                "Hex string \"0x1f\" has non-hex character 'x' at index 1",
+            ),
+            (
+                HexMatcher::new().lowercase(),
+                "9f86D081",
+                "Hex string \"9f86D081\" is not lowercase",
+            ),
+            (
+                HexMatcher::new().uppercase(),
+                "deadbeef",
+                "Hex string \"deadbeef\" is not uppercase",
+            ),
+            (
+                HexMatcher::new().exact_len(64),
+                "9f86d081",
+                "Hex string \"9f86d081\" has 8 digits, expected 64",
+            ),
+        ];
+        for (matcher, hex, message) in cases {
+            assert_eq!(
+                matcher.json_matches(&json!(hex)),
+                vec![JsonMatcherError::at_root(message)]
+            );
+        }
+        assert_eq!(
+            HexMatcher::new().json_matches(&json!(255)),
+            vec![JsonMatcherError::at_root("Expected string for hex")]
+        );
+    }
+}
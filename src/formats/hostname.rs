@@ -0,0 +1,149 @@
+use serde_json::Value;
+
+use crate::{JsonMatcher, JsonMatcherError};
+
+/// Checks a hostname against RFC 1123, returning what is wrong with it.
+pub(crate) fn check_hostname(host: &str) -> Result<(), String> {
+    if host.is_empty() {
+        return Err("is empty".to_string());
+    }
+    if host.len() > 253 {
+        return Err(format!("has {} characters, more than 253", host.len()));
+    }
+    if let Some(c) = host
+        .chars()
+        .find(|c| !c.is_ascii_alphanumeric() && *c != '-' && *c != '.')
+    {
+        return Err(format!("has invalid character {:?}", c));
+    }
+    for label in host.split('.') {
+        if label.is_empty() {
+            return Err("has an empty label".to_string());
+        }
+        if label.len() > 63 {
+            return Err(format!(
+                "has label \"{}\" with {} characters, more than 63",
+                label,
+                label.len()
+            ));
+        }
+        if label.starts_with('-') || label.ends_with('-') {
+            return Err(format!(
+                "has label \"{}\" starting or ending with a hyphen",
+                label
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Matches strings holding an RFC 1123 hostname, e.g. `"api.example.com"` or `"localhost"`.
+pub struct HostnameMatcher {
+    fully_qualified: bool,
+}
+
+impl Default for HostnameMatcher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl HostnameMatcher {
+    pub fn new() -> Self {
+        Self {
+            fully_qualified: false,
+        }
+    }
+
+    /// Requires at least two labels and a top-level label that is not numeric, which rules out
+    /// `"localhost"` and IPv4 addresses.
+    pub fn fully_qualified(mut self) -> Self {
+        self.fully_qualified = true;
+        self
+    }
+}
+
+impl JsonMatcher for HostnameMatcher {
+    fn json_matches(&self, value: &Value) -> Vec<JsonMatcherError> {
+        let Some(host) = value.as_str() else {
+            return vec![JsonMatcherError::at_root("Expected string for hostname")];
+        };
+        if let Err(problem) = check_hostname(host) {
+            return vec![JsonMatcherError::at_root(format!(
+                "Hostname \"{}\" {}",
+                host, problem
+            ))];
+        }
+        if self.fully_qualified {
+            let top_level = host.rsplit('.').next().unwrap_or(host);
+            if !host.contains('.') || top_level.bytes().all(|b| b.is_ascii_digit()) {
+                return vec![JsonMatcherError::at_root(format!(
+                    "Hostname \"{}\" is not fully qualified",
+                    host
+                ))];
+            }
+        }
+        vec![]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::assert_jm;
+
+    use super::*;
+
+    #[test]
+    fn test_hostname_matcher() {
+        for valid in ["localhost", "api.example.com", "xn--bcher-kva.de", "a-1.b2"] {
+            assert_jm!(json!(valid), HostnameMatcher::new());
+        }
+        let cases = [
+            ("", "Hostname \"\" is empty"),
+            (
+                "exa mple.com",
+                "Hostname \"exa mple.com\" has invalid character ' '",
+            ),
+            (
+                "example..com",
+                "Hostname \"example..com\" has an empty label",
+            ),
+            (
+                "-example.com",
+                "Hostname \"-example.com\" has label \"-example\" starting or ending with a hyphen",
+            ),
+        ];
+        for (host, message) in cases {
+            assert_eq!(
+                HostnameMatcher::new().json_matches(&json!(host)),
+                vec![JsonMatcherError::at_root(message)]
+            );
+        }
+        let long_label = "a".repeat(64);
+        assert_eq!(
+            HostnameMatcher::new().json_matches(&json!(format!("{}.com", long_label))),
+            vec![JsonMatcherError::at_root(format!(
+                "Hostname \"{}.com\" has label \"{}\" with 64 characters, more than 63",
+                long_label, long_label
+            ))]
+        );
+        assert_jm!(
+            json!("example.com"),
+            HostnameMatcher::new().fully_qualified()
+        );
+        assert_eq!(
+            HostnameMatcher::new()
+                .fully_qualified()
+                .json_matches(&json!("localhost")),
+            vec![JsonMatcherError::at_root(
+                "Hostname \"localhost\" is not fully qualified"
+            )]
+        );
+        assert_eq!(
+            HostnameMatcher::new().json_matches(&json!(1)),
+            vec![JsonMatcherError::at_root("Expected string for hostname")]
+        );
+    }
+}
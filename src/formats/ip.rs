@@ -0,0 +1,310 @@
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use serde_json::Value;
+
+use crate::{JsonMatcher, JsonMatcherError};
+
+#[derive(Clone, Copy, PartialEq)]
+enum IpVersion {
+    V4,
+    V6,
+    Any,
+}
+
+impl IpVersion {
+    fn name(self) -> &'static str {
+        match self {
+            IpVersion::V4 => "IPv4",
+            IpVersion::V6 => "IPv6",
+            IpVersion::Any => "IP",
+        }
+    }
+
+    fn parse(self, address: &str) -> Option<IpAddr> {
+        match self {
+            IpVersion::V4 => address.parse::<Ipv4Addr>().ok().map(IpAddr::V4),
+            IpVersion::V6 => address.parse::<Ipv6Addr>().ok().map(IpAddr::V6),
+            IpVersion::Any => address.parse::<IpAddr>().ok(),
+        }
+    }
+}
+
+/// Matches strings holding an IP address, such as `"192.0.2.1"` or `"2001:db8::1"`.
+///
+/// IPv4 addresses must be in dotted-decimal form without leading zeros and IPv6 addresses in
+/// RFC 4291 text form, without a zone or brackets.
+pub struct IpAddressMatcher {
+    version: IpVersion,
+}
+
+impl Default for IpAddressMatcher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl IpAddressMatcher {
+    /// Matches IPv4 and IPv6 addresses.
+    pub fn new() -> Self {
+        Self {
+            version: IpVersion::Any,
+        }
+    }
+
+    pub fn v4() -> Self {
+        Self {
+            version: IpVersion::V4,
+        }
+    }
+
+    pub fn v6() -> Self {
+        Self {
+            version: IpVersion::V6,
+        }
+    }
+}
+
+/// Explains why `address` is not an address of `version`.
+fn invalid_address(version: IpVersion, address: &str) -> String {
+    let other = match version {
+        IpVersion::V4 => address.parse::<Ipv6Addr>().is_ok().then_some("IPv6"),
+        IpVersion::V6 => address.parse::<Ipv4Addr>().is_ok().then_some("IPv4"),
+        IpVersion::Any => None,
+    };
+    match other {
+        Some(other) => format!(
+            "Expected {} address but got {} address \"{}\"",
+            version.name(),
+            other,
+            address
+        ),
+        None => format!("\"{}\" is not a valid {} address", address, version.name()),
+    }
+}
+
+impl JsonMatcher for IpAddressMatcher {
+    fn json_matches(&self, value: &Value) -> Vec<JsonMatcherError> {
+        let Some(address) = value.as_str() else {
+            return vec![JsonMatcherError::at_root(format!(
+                "Expected string for {} address",
+                self.version.name()
+            ))];
+        };
+        match self.version.parse(address) {
+            Some(_) => vec![],
+            None => vec![JsonMatcherError::at_root(invalid_address(
+                self.version,
+                address,
+            ))],
+        }
+    }
+}
+
+/// Matches strings holding a CIDR block, such as `"10.0.0.0/8"` or `"2001:db8::/32"`.
+///
+/// By default the address may have bits set beyond the prefix, as in the interface notation
+/// `"192.0.2.1/24"`; [`strict`](CidrMatcher::strict) rejects these.
+pub struct CidrMatcher {
+    version: IpVersion,
+    strict: bool,
+}
+
+impl Default for CidrMatcher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CidrMatcher {
+    /// Matches IPv4 and IPv6 CIDR blocks.
+    pub fn new() -> Self {
+        Self {
+            version: IpVersion::Any,
+            strict: false,
+        }
+    }
+
+    pub fn v4() -> Self {
+        Self {
+            version: IpVersion::V4,
+            strict: false,
+        }
+    }
+
+    pub fn v6() -> Self {
+        Self {
+            version: IpVersion::V6,
+            strict: false,
+        }
+    }
+
+    /// Requires all address bits beyond the prefix to be zero, so `"10.0.0.0/8"` matches but
+    /// `"10.0.0.1/8"` does not.
+    pub fn strict(mut self) -> Self {
+        self.strict = true;
+        self
+    }
+
+    fn check(&self, cidr: &str) -> Result<(), String> {
+        let Some((address, prefix)) = cidr.split_once('/') else {
+            return Err(format!("CIDR block \"{}\" has no prefix length", cidr));
+        };
+        let Some(ip) = self.version.parse(address) else {
+            return Err(format!(
+                "CIDR block \"{}\": {}",
+                cidr,
+                invalid_address(self.version, address)
+            ));
+        };
+        let max_prefix = if ip.is_ipv4() { 32 } else { 128 };
+        let digits = prefix.bytes().all(|b| b.is_ascii_digit());
+        let prefix = match prefix.parse::<u32>() {
+            Ok(length) if digits && length <= max_prefix => length,
+            _ => {
+                return Err(format!(
+                    "CIDR block \"{}\" has invalid prefix length \"{}\", expected 0 to {}",
+                    cidr, prefix, max_prefix
+                ))
+            }
+        };
+        if self.strict {
+            let (bits, width) = match ip {
+                IpAddr::V4(ip) => (u128::from(u32::from(ip)), 32),
+                IpAddr::V6(ip) => (u128::from(ip), 128),
+            };
+            if prefix < width && bits & (u128::MAX >> (128 - (width - prefix))) != 0 {
+                return Err(format!(
+                    "CIDR block \"{}\" has bits set beyond the /{} prefix",
+                    cidr, prefix
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+impl JsonMatcher for CidrMatcher {
+    fn json_matches(&self, value: &Value) -> Vec<JsonMatcherError> {
+        let Some(cidr) = value.as_str() else {
+            return vec![JsonMatcherError::at_root(format!(
+                "Expected string for {} CIDR block",
+                self.version.name()
+            ))];
+        };
+        match self.check(cidr) {
+            Ok(()) => vec![],
+            Err(message) => vec![JsonMatcherError::at_root(message)],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::assert_jm;
+
+    use super::*;
+
+    #[test]
+    fn test_ip_address_matcher() {
+        for valid in [
+            "192.0.2.1",
+            "0.0.0.0",
+            "::1",
+            "2001:db8::1",
+            "::ffff:192.0.2.1",
+        ] {
+            assert_jm!(json!(valid), IpAddressMatcher::new());
+        }
+        assert_jm!(json!("192.0.2.1"), IpAddressMatcher::v4());
+        assert_jm!(json!("2001:db8::1"), IpAddressMatcher::v6());
+        for invalid in [
+            "256.0.0.1",
+            "192.0.2",
+            "192.000.2.1",
+            "2001:db8::g",
+            "[::1]",
+            "",
+        ] {
+            assert_eq!(
+                IpAddressMatcher::new().json_matches(&json!(invalid)),
+                vec![JsonMatcherError::at_root(format!(
+                    "\"{}\" is not a valid IP address",
+                    invalid
+                ))]
+            );
+        }
+        assert_eq!(
+            IpAddressMatcher::v4().json_matches(&json!("::1")),
+            vec![JsonMatcherError::at_root(
+                "Expected IPv4 address but got IPv6 address \"::1\""
+            )]
+        );
+        assert_eq!(
+            IpAddressMatcher::v6().json_matches(&json!(3232235777_u32)),
+            vec![JsonMatcherError::at_root(
+                "Expected string for IPv6 address"
+            )]
+        );
+    }
+
+    #[test]
+    fn test_cidr_matcher() {
+        for valid in [
+            "10.0.0.0/8",
+            "192.0.2.1/24",
+            "0.0.0.0/0",
+            "2001:db8::/32",
+            "::1/128",
+        ] {
+            assert_jm!(json!(valid), CidrMatcher::new());
+        }
+        for valid in ["10.0.0.0/8", "192.0.2.1/32", "0.0.0.0/0", "2001:db8::/32"] {
+            assert_jm!(json!(valid), CidrMatcher::new().strict());
+        }
+        let cases = [
+            ("10.0.0.0", "CIDR block \"10.0.0.0\" has no prefix length"),
+            (
+                "10.0.0.0/33",
+                "CIDR block \"10.0.0.0/33\" has invalid prefix length \"33\", expected 0 to 32",
+            ),
+            (
+                "2001:db8::/x",
+                "CIDR block \"2001:db8::/x\" has invalid prefix length \"x\", expected 0 to 128",
+            ),
+            (
+                "10.0.0/8",
+                "CIDR block \"10.0.0/8\": \"10.0.0\" is not a valid IP address",
+            ),
+        ];
+        for (cidr, message) in cases {
+            assert_eq!(
+                CidrMatcher::new().json_matches(&json!(cidr)),
+                vec![JsonMatcherError::at_root(message)]
+            );
+        }
+        assert_eq!(
+            CidrMatcher::v4().json_matches(&json!("2001:db8::/32")),
+            vec![JsonMatcherError::at_root(
+                "CIDR block \"2001:db8::/32\": Expected IPv4 address but got IPv6 address \"2001:db8::\""
+            )]
+        );
+        assert_eq!(
+            CidrMatcher::new()
+                .strict()
+                .json_matches(&json!("10.0.0.1/8")),
+            vec![JsonMatcherError::at_root(
+                "CIDR block \"10.0.0.1/8\" has bits set beyond the /8 prefix"
+            )]
+        );
+        assert_eq!(
+            CidrMatcher::v6()
+                .strict()
+                .json_matches(&json!("2001:db8::1/64")),
+            vec![JsonMatcherError::at_root(
+                "CIDR block \"2001:db8::1/64\" has bits set beyond the /64 prefix"
+            )]
+        );
+    }
+}
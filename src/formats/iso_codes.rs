@@ -0,0 +1,298 @@
+use serde_json::Value;
+
+use crate::{JsonMatcher, JsonMatcherError};
+
+/// Checks `code` against a sorted table of uppercase or lowercase codes, returning what is wrong.
+fn check_code(code: &str, table: &[&str], uppercase: bool) -> Result<(), String> {
+    if table.binary_search(&code).is_ok() {
+        return Ok(());
+    }
+    let recased = if uppercase {
+        code.to_ascii_uppercase()
+    } else {
+        code.to_ascii_lowercase()
+    };
+    if table.binary_search(&recased.as_str()).is_ok() {
+        return Err(format!(
+            "is not {}, did you mean \"{}\"?",
+            if uppercase { "uppercase" } else { "lowercase" },
+            recased
+        ));
+    }
+    Err("is not a known code".to_string())
+}
+
+/// Matches strings holding an ISO 3166-1 country code, alpha-2 (`"US"`) by default or alpha-3
+/// (`"USA"`) with [`alpha3`](CountryCodeMatcher::alpha3). Codes must be uppercase.
+pub struct CountryCodeMatcher {
+    alpha3: bool,
+}
+
+impl Default for CountryCodeMatcher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CountryCodeMatcher {
+    pub fn new() -> Self {
+        Self { alpha3: false }
+    }
+
+    pub fn alpha3() -> Self {
+        Self { alpha3: true }
+    }
+}
+
+impl JsonMatcher for CountryCodeMatcher {
+    fn json_matches(&self, value: &Value) -> Vec<JsonMatcherError> {
+        let (table, other, name, other_name): (&[&str], &[&str], _, _) = if self.alpha3 {
+            (&COUNTRY_ALPHA_3, &COUNTRY_ALPHA_2, "alpha-3", "alpha-2")
+        } else {
+            (&COUNTRY_ALPHA_2, &COUNTRY_ALPHA_3, "alpha-2", "alpha-3")
+        };
+        let Some(code) = value.as_str() else {
+            return vec![JsonMatcherError::at_root(format!(
+                "Expected string for ISO 3166-1 {} country code",
+                name
+            ))];
+        };
+        let problem = match check_code(code, table, true) {
+            Ok(()) => return vec![],
+            Err(_) if check_code(code, other, true).is_ok() => {
+                format!("is an {} code, expected {}", other_name, name)
+            }
+            Err(problem) => problem,
+        };
+        vec![JsonMatcherError::at_root(format!(
+            "Country code \"{}\" {}",
+            code, problem
+        ))]
+    }
+}
+
+/// Matches strings holding an ISO 4217 currency code, such as `"EUR"`. Codes must be uppercase.
+pub struct CurrencyCodeMatcher {
+    _private: (),
+}
+
+impl Default for CurrencyCodeMatcher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CurrencyCodeMatcher {
+    pub fn new() -> Self {
+        Self { _private: () }
+    }
+}
+
+impl JsonMatcher for CurrencyCodeMatcher {
+    fn json_matches(&self, value: &Value) -> Vec<JsonMatcherError> {
+        let Some(code) = value.as_str() else {
+            return vec![JsonMatcherError::at_root(
+                "Expected string for ISO 4217 currency code",
+            )];
+        };
+        match check_code(code, &CURRENCY_CODES, true) {
+            Ok(()) => vec![],
+            Err(problem) => vec![JsonMatcherError::at_root(format!(
+                "Currency code \"{}\" {}",
+                code, problem
+            ))],
+        }
+    }
+}
+
+/// Matches strings holding a two-letter ISO 639-1 language code, such as `"en"`. Codes must be
+/// lowercase.
+pub struct LanguageCodeMatcher {
+    _private: (),
+}
+
+impl Default for LanguageCodeMatcher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LanguageCodeMatcher {
+    pub fn new() -> Self {
+        Self { _private: () }
+    }
+}
+
+impl JsonMatcher for LanguageCodeMatcher {
+    fn json_matches(&self, value: &Value) -> Vec<JsonMatcherError> {
+        let Some(code) = value.as_str() else {
+            return vec![JsonMatcherError::at_root(
+                "Expected string for ISO 639-1 language code",
+            )];
+        };
+        match check_code(code, &LANGUAGE_CODES, false) {
+            Ok(()) => vec![],
+            Err(problem) => vec![JsonMatcherError::at_root(format!(
+                "Language code \"{}\" {}",
+                code, problem
+            ))],
+        }
+    }
+}
+
+/// ISO 3166-1 alpha-2 country codes, sorted.
+const COUNTRY_ALPHA_2: [&str; 249] = [
+    "AD", "AE", "AF", "AG", "AI", "AL", "AM", "AO", "AQ", "AR", "AS", "AT", "AU", "AW", "AX", "AZ",
+    "BA", "BB", "BD", "BE", "BF", "BG", "BH", "BI", "BJ", "BL", "BM", "BN", "BO", "BQ", "BR", "BS",
+    "BT", "BV", "BW", "BY", "BZ", "CA", "CC", "CD", "CF", "CG", "CH", "CI", "CK", "CL", "CM", "CN",
+    "CO", "CR", "CU", "CV", "CW", "CX", "CY", "CZ", "DE", "DJ", "DK", "DM", "DO", "DZ", "EC", "EE",
+    "EG", "EH", "ER", "ES", "ET", "FI", "FJ", "FK", "FM", "FO", "FR", "GA", "GB", "GD", "GE", "GF",
+    "GG", "GH", "GI", "GL", "GM", "GN", "GP", "GQ", "GR", "GS", "GT", "GU", "GW", "GY", "HK", "HM",
+    "HN", "HR", "HT", "HU", "ID", "IE", "IL", "IM", "IN", "IO", "IQ", "IR", "IS", "IT", "JE", "JM",
+    "JO", "JP", "KE", "KG", "KH", "KI", "KM", "KN", "KP", "KR", "KW", "KY", "KZ", "LA", "LB", "LC",
+    "LI", "LK", "LR", "LS", "LT", "LU", "LV", "LY", "MA", "MC", "MD", "ME", "MF", "MG", "MH", "MK",
+    "ML", "MM", "MN", "MO", "MP", "MQ", "MR", "MS", "MT", "MU", "MV", "MW", "MX", "MY", "MZ", "NA",
+    "NC", "NE", "NF", "NG", "NI", "NL", "NO", "NP", "NR", "NU", "NZ", "OM", "PA", "PE", "PF", "PG",
+    "PH", "PK", "PL", "PM", "PN", "PR", "PS", "PT", "PW", "PY", "QA", "RE", "RO", "RS", "RU", "RW",
+    "SA", "SB", "SC", "SD", "SE", "SG", "SH", "SI", "SJ", "SK", "SL", "SM", "SN", "SO", "SR", "SS",
+    "ST", "SV", "SX", "SY", "SZ", "TC", "TD", "TF", "TG", "TH", "TJ", "TK", "TL", "TM", "TN", "TO",
+    "TR", "TT", "TV", "TW", "TZ", "UA", "UG", "UM", "US", "UY", "UZ", "VA", "VC", "VE", "VG", "VI",
+    "VN", "VU", "WF", "WS", "YE", "YT", "ZA", "ZM", "ZW",
+];
+
+/// ISO 3166-1 alpha-3 country codes, sorted.
+const COUNTRY_ALPHA_3: [&str; 249] = [
+    "ABW", "AFG", "AGO", "AIA", "ALA", "ALB", "AND", "ARE", "ARG", "ARM", "ASM", "ATA", "ATF",
+    "ATG", "AUS", "AUT", "AZE", "BDI", "BEL", "BEN", "BES", "BFA", "BGD", "BGR", "BHR", "BHS",
+    "BIH", "BLM", "BLR", "BLZ", "BMU", "BOL", "BRA", "BRB", "BRN", "BTN", "BVT", "BWA", "CAF",
+    "CAN", "CCK", "CHE", "CHL", "CHN", "CIV", "CMR", "COD", "COG", "COK", "COL", "COM", "CPV",
+    "CRI", "CUB", "CUW", "CXR", "CYM", "CYP", "CZE", "DEU", "DJI", "DMA", "DNK", "DOM", "DZA",
+    "ECU", "EGY", "ERI", "ESH", "ESP", "EST", "ETH", "FIN", "FJI", "FLK", "FRA", "FRO", "FSM",
+    "GAB", "GBR", "GEO", "GGY", "GHA", "GIB", "GIN", "GLP", "GMB", "GNB", "GNQ", "GRC", "GRD",
+    "GRL", "GTM", "GUF", "GUM", "GUY", "HKG", "HMD", "HND", "HRV", "HTI", "HUN", "IDN", "IMN",
+    "IND", "IOT", "IRL", "IRN", "IRQ", "ISL", "ISR", "ITA", "JAM", "JEY", "JOR", "JPN", "KAZ",
+    "KEN", "KGZ", "KHM", "KIR", "KNA", "KOR", "KWT", "LAO", "LBN", "LBR", "LBY", "LCA", "LIE",
+    "LKA", "LSO", "LTU", "LUX", "LVA", "MAC", "MAF", "MAR", "MCO", "MDA", "MDG", "MDV", "MEX",
+    "MHL", "MKD", "MLI", "MLT", "MMR", "MNE", "MNG", "MNP", "MOZ", "MRT", "MSR", "MTQ", "MUS",
+    "MWI", "MYS", "MYT", "NAM", "NCL", "NER", "NFK", "NGA", "NIC", "NIU", "NLD", "NOR", "NPL",
+    "NRU", "NZL", "OMN", "PAK", "PAN", "PCN", "PER", "PHL", "PLW", "PNG", "POL", "PRI", "PRK",
+    "PRT", "PRY", "PSE", "PYF", "QAT", "REU", "ROU", "RUS", "RWA", "SAU", "SDN", "SEN", "SGP",
+    "SGS", "SHN", "SJM", "SLB", "SLE", "SLV", "SMR", "SOM", "SPM", "SRB", "SSD", "STP", "SUR",
+    "SVK", "SVN", "SWE", "SWZ", "SXM", "SYC", "SYR", "TCA", "TCD", "TGO", "THA", "TJK", "TKL",
+    "TKM", "TLS", "TON", "TTO", "TUN", "TUR", "TUV", "TWN", "TZA", "UGA", "UKR", "UMI", "URY",
+    "USA", "UZB", "VAT", "VCT", "VEN", "VGB", "VIR", "VNM", "VUT", "WLF", "WSM", "YEM", "ZAF",
+    "ZMB", "ZWE",
+];
+
+/// ISO 4217 currency codes, sorted.
+const CURRENCY_CODES: [&str; 181] = [
+    "AED", "AFN", "ALL", "AMD", "ANG", "AOA", "ARS", "AUD", "AWG", "AZN", "BAM", "BBD", "BDT",
+    "BGN", "BHD", "BIF", "BMD", "BND", "BOB", "BOV", "BRL", "BSD", "BTN", "BWP", "BYN", "BZD",
+    "CAD", "CDF", "CHE", "CHF", "CHW", "CLF", "CLP", "CNY", "COP", "COU", "CRC", "CUC", "CUP",
+    "CVE", "CZK", "DJF", "DKK", "DOP", "DZD", "EGP", "ERN", "ETB", "EUR", "FJD", "FKP", "GBP",
+    "GEL", "GHS", "GIP", "GMD", "GNF", "GTQ", "GYD", "HKD", "HNL", "HRK", "HTG", "HUF", "IDR",
+    "ILS", "INR", "IQD", "IRR", "ISK", "JMD", "JOD", "JPY", "KES", "KGS", "KHR", "KMF", "KPW",
+    "KRW", "KWD", "KYD", "KZT", "LAK", "LBP", "LKR", "LRD", "LSL", "LYD", "MAD", "MDL", "MGA",
+    "MKD", "MMK", "MNT", "MOP", "MRU", "MUR", "MVR", "MWK", "MXN", "MXV", "MYR", "MZN", "NAD",
+    "NGN", "NIO", "NOK", "NPR", "NZD", "OMR", "PAB", "PEN", "PGK", "PHP", "PKR", "PLN", "PYG",
+    "QAR", "RON", "RSD", "RUB", "RWF", "SAR", "SBD", "SCR", "SDG", "SEK", "SGD", "SHP", "SLE",
+    "SLL", "SOS", "SRD", "SSP", "STN", "SVC", "SYP", "SZL", "THB", "TJS", "TMT", "TND", "TOP",
+    "TRY", "TTD", "TWD", "TZS", "UAH", "UGX", "USD", "USN", "UYI", "UYU", "UYW", "UZS", "VED",
+    "VES", "VND", "VUV", "WST", "XAF", "XAG", "XAU", "XBA", "XBB", "XBC", "XBD", "XCD", "XDR",
+    "XOF", "XPD", "XPF", "XPT", "XSU", "XTS", "XUA", "XXX", "YER", "ZAR", "ZMW", "ZWL",
+];
+
+/// ISO 639-1 language codes, sorted.
+const LANGUAGE_CODES: [&str; 184] = [
+    "aa", "ab", "ae", "af", "ak", "am", "an", "ar", "as", "av", "ay", "az", "ba", "be", "bg", "bh",
+    "bi", "bm", "bn", "bo", "br", "bs", "ca", "ce", "ch", "co", "cr", "cs", "cu", "cv", "cy", "da",
+    "de", "dv", "dz", "ee", "el", "en", "eo", "es", "et", "eu", "fa", "ff", "fi", "fj", "fo", "fr",
+    "fy", "ga", "gd", "gl", "gn", "gu", "gv", "ha", "he", "hi", "ho", "hr", "ht", "hu", "hy", "hz",
+    "ia", "id", "ie", "ig", "ii", "ik", "io", "is", "it", "iu", "ja", "jv", "ka", "kg", "ki", "kj",
+    "kk", "kl", "km", "kn", "ko", "kr", "ks", "ku", "kv", "kw", "ky", "la", "lb", "lg", "li", "ln",
+    "lo", "lt", "lu", "lv", "mg", "mh", "mi", "mk", "ml", "mn", "mr", "ms", "mt", "my", "na", "nb",
+    "nd", "ne", "ng", "nl", "nn", "no", "nr", "nv", "ny", "oc", "oj", "om", "or", "os", "pa", "pi",
+    "pl", "ps", "pt", "qu", "rm", "rn", "ro", "ru", "rw", "sa", "sc", "sd", "se", "sg", "si", "sk",
+    "sl", "sm", "sn", "so", "sq", "sr", "ss", "st", "su", "sv", "sw", "ta", "te", "tg", "th", "ti",
+    "tk", "tl", "tn", "to", "tr", "ts", "tt", "tw", "ty", "ug", "uk", "ur", "uz", "ve", "vi", "vo",
+    "wa", "wo", "xh", "yi", "yo", "za", "zh", "zu",
+];
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::assert_jm;
+
+    use super::*;
+
+    #[test]
+    fn test_tables_are_sorted() {
+        for table in [
+            &COUNTRY_ALPHA_2[..],
+            &COUNTRY_ALPHA_3,
+            &CURRENCY_CODES,
+            &LANGUAGE_CODES,
+        ] {
+            assert!(table.windows(2).all(|pair| pair[0] < pair[1]));
+        }
+    }
+
+    #[test]
+    fn test_iso_code_matchers() {
+        assert_jm!(json!("US"), CountryCodeMatcher::new());
+        assert_jm!(json!("DEU"), CountryCodeMatcher::alpha3());
+        assert_eq!(
+            CountryCodeMatcher::new().json_matches(&json!("us")),
+            vec![JsonMatcherError::at_root(
+                "Country code \"us\" is not uppercase, did you mean \"US\"?"
+            )]
+        );
+        assert_eq!(
+            CountryCodeMatcher::new().json_matches(&json!("USA")),
+            vec![JsonMatcherError::at_root(
+                "Country code \"USA\" is an alpha-3 code, expected alpha-2"
+            )]
+        );
+        assert_eq!(
+            CountryCodeMatcher::alpha3().json_matches(&json!("XYZ")),
+            vec![JsonMatcherError::at_root(
+                "Country code \"XYZ\" is not a known code"
+            )]
+        );
+
+        assert_jm!(json!("EUR"), CurrencyCodeMatcher::new());
+        assert_eq!(
+            CurrencyCodeMatcher::new().json_matches(&json!("usd")),
+            vec![JsonMatcherError::at_root(
+                "Currency code \"usd\" is not uppercase, did you mean \"USD\"?"
+            )]
+        );
+        assert_eq!(
+            CurrencyCodeMatcher::new().json_matches(&json!("EURO")),
+            vec![JsonMatcherError::at_root(
+                "Currency code \"EURO\" is not a known code"
+            )]
+        );
+
+        assert_jm!(json!("en"), LanguageCodeMatcher::new());
+        assert_eq!(
+            LanguageCodeMatcher::new().json_matches(&json!("FR")),
+            vec![JsonMatcherError::at_root(
+                "Language code \"FR\" is not lowercase, did you mean \"fr\"?"
+            )]
+        );
+        assert_eq!(
+            LanguageCodeMatcher::new().json_matches(&json!("eng")),
+            vec![JsonMatcherError::at_root(
+                "Language code \"eng\" is not a known code"
+            )]
+        );
+        assert_eq!(
+            LanguageCodeMatcher::new().json_matches(&json!(null)),
+            vec![JsonMatcherError::at_root(
+                "Expected string for ISO 639-1 language code"
+            )]
+        );
+    }
+}
@@ -0,0 +1,213 @@
+use serde_json::Value;
+
+use super::base64::{Base64Format, Base64Padding};
+use crate::{JsonMatcher, JsonMatcherError, JsonPath, JsonPathElement};
+
+/// Matches strings holding JSON, as sent by APIs that double-encode, and applies a matcher to the
+/// parsed value:
+///
+/// ```
+/// use serde_json::json;
+/// use json_matcher::{assert_jm, create_json_matcher, AnyMatcher};
+/// use json_matcher::formats::JsonStringMatcher;
+///
+/// assert_jm!(json!({
+///     "metadata": "{\"source\":\"import\",\"rows\":12}",
+///     "event": "eyJ0eXBlIjoiY3JlYXRlZCJ9"
+/// }), {
+///     "metadata": JsonStringMatcher::new(create_json_matcher!({
+///         "source": "import",
+///         "rows": AnyMatcher::not_null()
+///     })),
+///     "event": JsonStringMatcher::new(json!({ "type": "created" })).base64()
+/// });
+/// ```
+///
+/// Errors from the matcher are reported below the decode steps in the string's path, so a wrong
+/// `source` above is reported at `$.metadata<json>.source`, and a wrong `type` at
+/// `$.event<base64><json>.type`.
+pub struct JsonStringMatcher {
+    matcher: Option<Box<dyn JsonMatcher>>,
+    base64: Option<Base64Format>,
+}
+
+impl JsonStringMatcher {
+    pub fn new(matcher: impl JsonMatcher + 'static) -> Self {
+        Self {
+            matcher: Some(Box::new(matcher)),
+            base64: None,
+        }
+    }
+
+    /// Matches strings holding any valid JSON.
+    pub fn any() -> Self {
+        Self {
+            matcher: None,
+            base64: None,
+        }
+    }
+
+    /// Decodes the string from standard base64, with or without padding, before parsing it.
+    pub fn base64(mut self) -> Self {
+        self.base64 = Some(Base64Format {
+            url_safe: false,
+            padding: Base64Padding::Optional,
+        });
+        self
+    }
+
+    /// Decodes the string from URL-safe base64, with or without padding, before parsing it.
+    pub fn base64url(mut self) -> Self {
+        self.base64 = Some(Base64Format {
+            url_safe: true,
+            padding: Base64Padding::Optional,
+        });
+        self
+    }
+}
+
+impl JsonMatcher for JsonStringMatcher {
+    fn json_matches(&self, value: &Value) -> Vec<JsonMatcherError> {
+        let Some(s) = value.as_str() else {
+            return vec![JsonMatcherError::at_root("Expected string holding JSON")];
+        };
+        let mut this_path = vec![JsonPathElement::Root];
+        let parsed = match self.base64 {
+            Some(format) => {
+                let bytes = match format.decode(s) {
+                    Ok(bytes) => bytes,
+                    Err(problem) => {
+                        return vec![JsonMatcherError::at_root(format!(
+                            "{} string \"{}\" {}",
+                            format.label(),
+                            s,
+                            problem
+                        ))]
+                    }
+                };
+                this_path.push(JsonPathElement::Decoded(format.name().to_string()));
+                serde_json::from_slice::<Value>(&bytes)
+            }
+            None => serde_json::from_str::<Value>(s),
+        };
+        let parsed = match parsed {
+            Ok(parsed) => parsed,
+            Err(e) => {
+                let source = match self.base64 {
+                    Some(format) => format!("Decoded {}", format.name()),
+                    None => "String".to_string(),
+                };
+                return vec![JsonMatcherError::at_root(format!(
+                    "{} is not valid JSON: {}",
+                    source, e
+                ))];
+            }
+        };
+        let Some(matcher) = &self.matcher else {
+            return vec![];
+        };
+        this_path.push(JsonPathElement::Decoded("json".to_string()));
+        let this_path = JsonPath::from(this_path);
+        matcher
+            .json_matches(&parsed)
+            .into_iter()
+            .map(|JsonMatcherError { path, message }| JsonMatcherError {
+                path: this_path.clone().extend(path),
+                message,
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::assert_jm;
+    use crate::test::catch_string_panic;
+
+    use super::*;
+
+    #[test]
+    fn test_json_string_matcher() {
+        let matcher = JsonStringMatcher::new(json!({ "a": 1 }));
+        assert_jm!(json!("{\"a\":1}"), matcher);
+        assert_jm!(json!(" { \"a\" : 1 } "), matcher);
+        assert_jm!(json!("[1, null]"), JsonStringMatcher::any());
+        assert_eq!(
+            matcher.json_matches(&json!("{\"a\":2}")),
+            vec![JsonMatcherError {
+                path: JsonPath::from(vec![
+                    JsonPathElement::Root,
+                    JsonPathElement::Decoded("json".to_string()),
+                    JsonPathElement::Key("a".to_string()),
+                ]),
+                message: "Expected integer 1 but got 2".to_string(),
+            }]
+        );
+        assert_eq!(
+            matcher.json_matches(&json!("{a:1}")),
+            vec![JsonMatcherError::at_root(
+                "String is not valid JSON: key must be a string at line 1 column 2"
+            )]
+        );
+        assert_eq!(
+            matcher.json_matches(&json!({ "a": 1 })),
+            vec![JsonMatcherError::at_root("Expected string holding JSON")]
+        );
+        assert_eq!(
+            catch_string_panic(|| assert_jm!(
+                json!({ "metadata": "{\"tags\":[\"x\"]}" }),
+                { "metadata": JsonStringMatcher::new(json!({ "tags": ["y"] })) }
+            ))
+            .lines()
+            .nth(2),
+            Some("  - $.metadata<json>.tags.0: Expected string \"y\" but got \"x\"")
+        );
+    }
+
+    #[test]
+    fn test_base64_json_string_matcher() {
+        // {"type":"created"}
+        let encoded = "eyJ0eXBlIjoiY3JlYXRlZCJ9";
+        assert_jm!(
+            json!(encoded),
+            JsonStringMatcher::new(json!({ "type": "created" })).base64()
+        );
+        assert_jm!(
+            json!(encoded),
+            JsonStringMatcher::new(json!({ "type": "created" })).base64url()
+        );
+        assert_eq!(
+            JsonStringMatcher::new(json!({ "type": "deleted" }))
+                .base64()
+                .json_matches(&json!(encoded)),
+            vec![JsonMatcherError {
+                path: JsonPath::from(vec![
+                    JsonPathElement::Root,
+                    JsonPathElement::Decoded("base64".to_string()),
+                    JsonPathElement::Decoded("json".to_string()),
+                    JsonPathElement::Key("type".to_string()),
+                ]),
+                message: "Expected string \"deleted\" but got \"created\"".to_string(),
+            }]
+        );
+        assert_eq!(
+            JsonStringMatcher::any()
+                .base64()
+                .json_matches(&json!("{\"type\":1}")),
+            vec![JsonMatcherError::at_root(
+                "Base64 string \"{\"type\":1}\" has invalid character '{' at index 0"
+            )]
+        );
+        // "not json"
+        assert_eq!(
+            JsonStringMatcher::any()
+                .base64()
+                .json_matches(&json!("bm90IGpzb24=")),
+            vec![JsonMatcherError::at_root(
+                "Decoded base64 is not valid JSON: expected ident at line 1 column 2"
+            )]
+        );
+    }
+}
@@ -0,0 +1,125 @@
+use serde_json::Value;
+
+use crate::{JsonMatcher, JsonMatcherError};
+
+/// Matches strings holding a 48-bit MAC address of six hexadecimal octets, such as
+/// `"00:1a:2b:3c:4d:5e"` or `"00-1A-2B-3C-4D-5E"`.
+///
+/// Octets may be separated by `:` or `-` but not a mix of both; use
+/// [`separator`](MacAddressMatcher::separator) to require one of them.
+pub struct MacAddressMatcher {
+    separator: Option<char>,
+}
+
+impl Default for MacAddressMatcher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MacAddressMatcher {
+    pub fn new() -> Self {
+        Self { separator: None }
+    }
+
+    /// Requires octets to be separated by `separator`, which is usually `':'` or `'-'`.
+    pub fn separator(mut self, separator: char) -> Self {
+        self.separator = Some(separator);
+        self
+    }
+
+    fn check(&self, mac: &str) -> Result<(), String> {
+        let separator = match self.separator {
+            Some(separator) => separator,
+            None => match mac.chars().find(|c| *c == ':' || *c == '-') {
+                Some(separator) => separator,
+                None => return Err("has no separators between octets".to_string()),
+            },
+        };
+        let octets: Vec<&str> = mac.split(separator).collect();
+        if octets.len() != 6 {
+            return Err(format!(
+                "has {} octets separated by {:?}, expected 6",
+                octets.len(),
+                separator
+            ));
+        }
+        if let Some(octet) = octets
+            .iter()
+            .find(|octet| octet.len() != 2 || !octet.bytes().all(|b| b.is_ascii_hexdigit()))
+        {
+            return Err(format!("has invalid octet \"{}\"", octet));
+        }
+        Ok(())
+    }
+}
+
+impl JsonMatcher for MacAddressMatcher {
+    fn json_matches(&self, value: &Value) -> Vec<JsonMatcherError> {
+        let Some(mac) = value.as_str() else {
+            return vec![JsonMatcherError::at_root("Expected string for MAC address")];
+        };
+        match self.check(mac) {
+            Ok(()) => vec![],
+            Err(problem) => vec![JsonMatcherError::at_root(format!(
+                "MAC address \"{}\" {}",
+                mac, problem
+            ))],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::assert_jm;
+
+    use super::*;
+
+    #[test]
+    fn test_mac_address_matcher() {
+        assert_jm!(json!("00:1a:2b:3c:4d:5e"), MacAddressMatcher::new());
+        assert_jm!(json!("00-1A-2B-3C-4D-5E"), MacAddressMatcher::new());
+        let cases = [
+            (
+                "001a2b3c4d5e",
+                "MAC address \"001a2b3c4d5e\" has no separators between octets",
+            ),
+            (
+                "00:1a:2b-3c:4d:5e",
+                "MAC address \"00:1a:2b-3c:4d:5e\" has 5 octets separated by ':', expected 6",
+            ),
+            (
+                "00:1a:2b:3c:4d",
+                "MAC address \"00:1a:2b:3c:4d\" has 5 octets separated by ':', expected 6",
+            ),
+            (
+                "00:1a:2b:3c:4d:5g",
+                "MAC address \"00:1a:2b:3c:4d:5g\" has invalid octet \"5g\"",
+            ),
+            (
+                "0:1a:2b:3c:4d:5e",
+                "MAC address \"0:1a:2b:3c:4d:5e\" has invalid octet \"0\"",
+            ),
+        ];
+        for (mac, message) in cases {
+            assert_eq!(
+                MacAddressMatcher::new().json_matches(&json!(mac)),
+                vec![JsonMatcherError::at_root(message)]
+            );
+        }
+        assert_eq!(
+            MacAddressMatcher::new()
+                .separator(':')
+                .json_matches(&json!("00-1a-2b-3c-4d-5e")),
+            vec![JsonMatcherError::at_root(
+                "MAC address \"00-1a-2b-3c-4d-5e\" has 1 octets separated by ':', expected 6"
+            )]
+        );
+        assert_eq!(
+            MacAddressMatcher::new().json_matches(&json!([0, 26, 43, 60, 77, 94])),
+            vec![JsonMatcherError::at_root("Expected string for MAC address")]
+        );
+    }
+}
@@ -0,0 +1,47 @@
+//! Matchers for strings in well-known formats: email addresses, URLs, IP addresses and CIDR blocks,
+//! hostnames, MAC addresses, E.164 phone numbers, ISO country, currency and language codes, and
+//! encodings such as base64, hex, JSON Web Tokens and JSON embedded in strings.
+//!
+//! ```
+//! use serde_json::json;
+//! use json_matcher::assert_jm;
+//! use json_matcher::formats::{CurrencyCodeMatcher, EmailMatcher, IpAddressMatcher, UrlMatcher};
+//!
+//! assert_jm!(json!({
+//!     "email": "ada@example.com",
+//!     "avatar": "https://cdn.example.com/u/1.png",
+//!     "last_ip": "2001:db8::1",
+//!     "currency": "EUR"
+//! }), {
+//!     "email": EmailMatcher::new(),
+//!     "avatar": UrlMatcher::new().scheme("https"),
+//!     "last_ip": IpAddressMatcher::v6(),
+//!     "currency": CurrencyCodeMatcher::new()
+//! });
+//! ```
+//!
+//! The validators are written against the relevant standards without external dependencies, and
+//! accept the forms in common use rather than every corner of each grammar.
+
+mod hostname;
+pub use hostname::*;
+mod email;
+pub use email::*;
+mod url;
+pub use url::*;
+mod ip;
+pub use ip::*;
+mod mac;
+pub use mac::*;
+mod phone;
+pub use phone::*;
+mod iso_codes;
+pub use iso_codes::*;
+mod base64;
+pub use base64::*;
+mod hex;
+pub use hex::*;
+mod jwt;
+pub use jwt::*;
+mod json_string;
+pub use json_string::*;
@@ -0,0 +1,125 @@
+use serde_json::Value;
+
+use crate::{JsonMatcher, JsonMatcherError};
+
+/// Matches strings holding a phone number in E.164 format: a `+` followed by at most 15 digits,
+/// the first of which is not zero, such as `"+14155552671"`. Spaces, dashes and parentheses are
+/// not allowed.
+pub struct E164Matcher {
+    country_code: Option<String>,
+}
+
+impl Default for E164Matcher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl E164Matcher {
+    pub fn new() -> Self {
+        Self { country_code: None }
+    }
+
+    /// Requires the number to start with this country calling code, given with or without the
+    /// `+`, e.g. `"1"` or `"+44"`.
+    pub fn country_code(mut self, code: impl Into<String>) -> Self {
+        let code = code.into();
+        self.country_code = Some(code.strip_prefix('+').unwrap_or(&code).to_string());
+        self
+    }
+
+    fn check(&self, phone: &str) -> Result<(), String> {
+        let Some(digits) = phone.strip_prefix('+') else {
+            return Err("does not start with +".to_string());
+        };
+        if let Some(c) = digits.chars().find(|c| !c.is_ascii_digit()) {
+            return Err(format!("has non-digit character {:?}", c));
+        }
+        if digits.is_empty() || digits.len() > 15 {
+            return Err(format!("has {} digits, expected 1 to 15", digits.len()));
+        }
+        if digits.starts_with('0') {
+            return Err("has a country code starting with 0".to_string());
+        }
+        if let Some(code) = &self.country_code {
+            if !digits.starts_with(code.as_str()) {
+                return Err(format!("does not have country code +{}", code));
+            }
+        }
+        Ok(())
+    }
+}
+
+impl JsonMatcher for E164Matcher {
+    fn json_matches(&self, value: &Value) -> Vec<JsonMatcherError> {
+        let Some(phone) = value.as_str() else {
+            return vec![JsonMatcherError::at_root(
+                "Expected string for E.164 phone number",
+            )];
+        };
+        match self.check(phone) {
+            Ok(()) => vec![],
+            Err(problem) => vec![JsonMatcherError::at_root(format!(
+                "Phone number \"{}\" {}",
+                phone, problem
+            ))],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::assert_jm;
+
+    use super::*;
+
+    #[test]
+    fn test_e164_matcher() {
+        assert_jm!(json!("+14155552671"), E164Matcher::new());
+        assert_jm!(
+            json!("+442071838750"),
+            E164Matcher::new().country_code("+44")
+        );
+        let cases = [
+            (
+                "14155552671",
+                "Phone number \"14155552671\" does not start with +",
+            ),
+            (
+                "+1 415 555 2671",
+                "Phone number \"+1 415 555 2671\" has non-digit character ' '",
+            ),
+            ("+", "Phone number \"+\" has 0 digits, expected 1 to 15"),
+            (
+                "+1234567890123456",
+                "Phone number \"+1234567890123456\" has 16 digits, expected 1 to 15",
+            ),
+            (
+                "+04155552671",
+                "Phone number \"+04155552671\" has a country code starting with 0",
+            ),
+        ];
+        for (phone, message) in cases {
+            assert_eq!(
+                E164Matcher::new().json_matches(&json!(phone)),
+                vec![JsonMatcherError::at_root(message)]
+            );
+        }
+        assert_eq!(
+            E164Matcher::new()
+                .country_code("1")
+                .json_matches(&json!("+442071838750")),
+            vec![JsonMatcherError::at_root(
+                "Phone number \"+442071838750\" does not have country code +1"
+            )]
+        );
+        assert_eq!(
+            E164Matcher::new().json_matches(&json!(14155552671_u64)),
+            vec![JsonMatcherError::at_root(
+                "Expected string for E.164 phone number"
+            )]
+        );
+    }
+}
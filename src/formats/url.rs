@@ -0,0 +1,368 @@
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+use serde_json::Value;
+
+use super::hostname::check_hostname;
+use crate::{JsonMatcher, JsonMatcherError};
+
+#[derive(Clone, Copy, PartialEq)]
+enum UrlKind {
+    Absolute,
+    Relative,
+    Any,
+}
+
+/// Matches strings holding an RFC 3986 URL, optionally constraining its scheme, host and path:
+///
+/// ```
+/// use serde_json::json;
+/// use json_matcher::assert_jm;
+/// use json_matcher::formats::UrlMatcher;
+///
+/// assert_jm!(json!({
+///     "self": "https://api.example.com/v1/users/7?expand=org",
+///     "next": "/v1/users?page=2"
+/// }), {
+///     "self": UrlMatcher::new().scheme("https").host("api.example.com").path_prefix("/v1"),
+///     "next": UrlMatcher::relative().path_prefix("/v1/users")
+/// });
+/// ```
+///
+/// Hosts may be hostnames, IPv4 addresses or bracketed IPv6 addresses, and ports must be at most
+/// 65535. Characters outside those allowed in URLs, such as spaces, must be percent-encoded.
+pub struct UrlMatcher {
+    kind: UrlKind,
+    schemes: Vec<String>,
+    host: Option<String>,
+    path_prefix: Option<String>,
+}
+
+impl Default for UrlMatcher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+struct Url<'a> {
+    scheme: Option<&'a str>,
+    host: Option<&'a str>,
+    path: &'a str,
+}
+
+impl UrlMatcher {
+    /// Matches absolute URLs, which start with a scheme such as `https:`.
+    pub fn new() -> Self {
+        Self {
+            kind: UrlKind::Absolute,
+            schemes: vec![],
+            host: None,
+            path_prefix: None,
+        }
+    }
+
+    /// Matches relative references such as `/users/7`, `../img.png` or `?page=2`.
+    pub fn relative() -> Self {
+        Self {
+            kind: UrlKind::Relative,
+            ..Self::new()
+        }
+    }
+
+    /// Matches both absolute URLs and relative references.
+    pub fn any() -> Self {
+        Self {
+            kind: UrlKind::Any,
+            ..Self::new()
+        }
+    }
+
+    /// Requires an absolute URL with this scheme, compared case-insensitively. Calling this more
+    /// than once allows any of the given schemes.
+    pub fn scheme(mut self, scheme: impl Into<String>) -> Self {
+        self.schemes.push(scheme.into().to_ascii_lowercase());
+        self
+    }
+
+    /// Requires a URL with this host, compared case-insensitively.
+    pub fn host(mut self, host: impl Into<String>) -> Self {
+        self.host = Some(host.into().to_ascii_lowercase());
+        self
+    }
+
+    /// Requires a path equal to `prefix` or continuing it with further segments, so `"/api"`
+    /// matches `/api` and `/api/users` but not `/apiv2`.
+    pub fn path_prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.path_prefix = Some(prefix.into());
+        self
+    }
+
+    fn check(&self, url: &str) -> Result<(), String> {
+        let parsed = parse_url(url)?;
+        match (self.kind, parsed.scheme) {
+            (UrlKind::Absolute, None) => return Err("is not absolute".to_string()),
+            (UrlKind::Relative, Some(_)) => return Err("is not relative".to_string()),
+            _ => {}
+        }
+        if !self.schemes.is_empty() {
+            let Some(scheme) = parsed.scheme else {
+                return Err("has no scheme".to_string());
+            };
+            if !self.schemes.contains(&scheme.to_ascii_lowercase()) {
+                let expected = self
+                    .schemes
+                    .iter()
+                    .map(|scheme| format!("\"{}\"", scheme))
+                    .collect::<Vec<_>>()
+                    .join(" or ");
+                return Err(format!(
+                    "has scheme \"{}\" but expected {}",
+                    scheme, expected
+                ));
+            }
+        }
+        if let Some(expected) = &self.host {
+            let Some(host) = parsed.host else {
+                return Err("has no host".to_string());
+            };
+            if !host.eq_ignore_ascii_case(expected) {
+                return Err(format!(
+                    "has host \"{}\" but expected \"{}\"",
+                    host, expected
+                ));
+            }
+        }
+        if let Some(prefix) = &self.path_prefix {
+            let within = parsed
+                .path
+                .strip_prefix(prefix.as_str())
+                .is_some_and(|rest| {
+                    rest.is_empty() || prefix.ends_with('/') || rest.starts_with('/')
+                });
+            if !within {
+                return Err(format!(
+                    "has path \"{}\" outside \"{}\"",
+                    parsed.path, prefix
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+fn is_url_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || "-._~!$&'()*+,;=:/?#[]@%".contains(c)
+}
+
+fn parse_url(url: &str) -> Result<Url<'_>, String> {
+    if url.is_empty() {
+        return Err("is empty".to_string());
+    }
+    if let Some(c) = url.chars().find(|c| !is_url_char(*c)) {
+        return Err(format!("has invalid character {:?}", c));
+    }
+    let bytes = url.as_bytes();
+    for (i, b) in bytes.iter().enumerate() {
+        if *b == b'%'
+            && !(bytes.get(i + 1).is_some_and(u8::is_ascii_hexdigit)
+                && bytes.get(i + 2).is_some_and(u8::is_ascii_hexdigit))
+        {
+            return Err("has invalid percent-encoding".to_string());
+        }
+    }
+    // a colon before any '/', '?' or '#' ends the scheme
+    let mut scheme = None;
+    let mut rest = url;
+    if let Some(colon) = url.find(':') {
+        if !url[..colon].contains(['/', '?', '#']) {
+            let candidate = &url[..colon];
+            let valid = candidate.starts_with(|c: char| c.is_ascii_alphabetic())
+                && candidate
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || "+-.".contains(c));
+            if !valid {
+                return Err(format!("has invalid scheme \"{}\"", candidate));
+            }
+            scheme = Some(candidate);
+            rest = &url[colon + 1..];
+        }
+    }
+    let mut host = None;
+    if let Some(after_slashes) = rest.strip_prefix("//") {
+        let end = after_slashes
+            .find(['/', '?', '#'])
+            .unwrap_or(after_slashes.len());
+        let authority = &after_slashes[..end];
+        let host_port = match authority.rsplit_once('@') {
+            Some((_, host_port)) => host_port,
+            None => authority,
+        };
+        let parsed_host = check_host_port(host_port)?;
+        if parsed_host.is_empty() && !scheme.is_some_and(|s| s.eq_ignore_ascii_case("file")) {
+            return Err("has an empty host".to_string());
+        }
+        host = Some(parsed_host);
+        rest = &after_slashes[end..];
+    }
+    let path = &rest[..rest.find(['?', '#']).unwrap_or(rest.len())];
+    if url[..url.find('#').unwrap_or(url.len())].contains(['[', ']'])
+        && !host.is_some_and(|host| host.starts_with('['))
+    {
+        return Err("has brackets outside the host".to_string());
+    }
+    Ok(Url { scheme, host, path })
+}
+
+/// Checks the host and port of an authority, returning the host.
+fn check_host_port(host_port: &str) -> Result<&str, String> {
+    let (host, port) = if host_port.starts_with('[') {
+        let Some(close) = host_port.find(']') else {
+            return Err(format!("has invalid host \"{}\"", host_port));
+        };
+        let host = &host_port[..=close];
+        if host[1..close].parse::<Ipv6Addr>().is_err() {
+            return Err(format!("has invalid IPv6 host \"{}\"", host));
+        }
+        match &host_port[close + 1..] {
+            "" => (host, None),
+            rest => match rest.strip_prefix(':') {
+                Some(port) => (host, Some(port)),
+                None => return Err(format!("has invalid host \"{}\"", host_port)),
+            },
+        }
+    } else {
+        match host_port.split_once(':') {
+            Some((host, port)) => (host, Some(port)),
+            None => (host_port, None),
+        }
+    };
+    if !host.is_empty() && !host.starts_with('[') {
+        if host.bytes().all(|b| b.is_ascii_digit() || b == b'.') {
+            if host.parse::<Ipv4Addr>().is_err() {
+                return Err(format!("has invalid IPv4 host \"{}\"", host));
+            }
+        } else if let Err(problem) = check_hostname(host) {
+            return Err(format!("has host \"{}\" that {}", host, problem));
+        }
+    }
+    if let Some(port) = port.filter(|port| !port.is_empty()) {
+        if !port.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(format!("has invalid port \"{}\"", port));
+        }
+        if port.parse::<u16>().is_err() {
+            return Err(format!("has port {}, more than 65535", port));
+        }
+    }
+    Ok(host)
+}
+
+impl JsonMatcher for UrlMatcher {
+    fn json_matches(&self, value: &Value) -> Vec<JsonMatcherError> {
+        let Some(url) = value.as_str() else {
+            return vec![JsonMatcherError::at_root("Expected string for URL")];
+        };
+        match self.check(url) {
+            Ok(()) => vec![],
+            Err(problem) => vec![JsonMatcherError::at_root(format!(
+                "URL \"{}\" {}",
+                url, problem
+            ))],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::assert_jm;
+
+    use super::*;
+
+    #[test]
+    fn test_url_matcher() {
+        for valid in [
+            "https://example.com",
+            "http://user:pw@localhost:8080/a/b?q=1#top",
+            "http://[2001:db8::1]:443/",
+            "http://192.0.2.1/%20x",
+            "mailto:ada@example.com",
+            "file:///etc/hosts",
+        ] {
+            assert_jm!(json!(valid), UrlMatcher::new());
+            assert_jm!(json!(valid), UrlMatcher::any());
+        }
+        for valid in [
+            "/users/7",
+            "../img.png",
+            "?page=2",
+            "#top",
+            "//cdn.example.com/a.js",
+        ] {
+            assert_jm!(json!(valid), UrlMatcher::relative());
+            assert_jm!(json!(valid), UrlMatcher::any());
+        }
+        let cases = [
+            ("", "URL \"\" is empty"),
+            ("/users", "URL \"/users\" is not absolute"),
+            ("https://exa mple.com", "URL \"https://exa mple.com\" has invalid character ' '"),
+            ("https://example.com/%zz", "URL \"https://example.com/%zz\" has invalid percent-encoding"),
+            ("1http://example.com", "URL \"1http://example.com\" has invalid scheme \"1http\""),
+            ("https://", "URL \"https://\" has an empty host"),
+            ("https://example.com:70000", "URL \"https://example.com:70000\" has port 70000, more than 65535"),
+            ("https://example.com:http", "URL \"https://example.com:http\" has invalid port \"http\""),
+            ("https://256.1.1.1", "URL \"https://256.1.1.1\" has invalid IPv4 host \"256.1.1.1\""),
+            ("https://[::g]", "URL \"https://[::g]\" has invalid IPv6 host \"[::g]\""),
+            ("https://-a.com", "URL \"https://-a.com\" has host \"-a.com\" that has label \"-a\" starting or ending with a hyphen"),
+        ];
+        for (url, message) in cases {
+            assert_eq!(
+                UrlMatcher::new().json_matches(&json!(url)),
+                vec![JsonMatcherError::at_root(message)]
+            );
+        }
+        assert_eq!(
+            UrlMatcher::relative().json_matches(&json!("https://example.com")),
+            vec![JsonMatcherError::at_root(
+                "URL \"https://example.com\" is not relative"
+            )]
+        );
+
+        let api = UrlMatcher::new()
+            .scheme("https")
+            .scheme("wss")
+            .host("api.example.com")
+            .path_prefix("/v1");
+        assert_jm!(json!("HTTPS://API.example.com/v1/users"), api);
+        assert_jm!(json!("wss://api.example.com/v1"), api);
+        assert_eq!(
+            api.json_matches(&json!("http://api.example.com/v1")),
+            vec![JsonMatcherError::at_root(
+                "URL \"http://api.example.com/v1\" has scheme \"http\" but expected \"https\" or \"wss\""
+            )]
+        );
+        assert_eq!(
+            api.json_matches(&json!("https://example.com/v1")),
+            vec![JsonMatcherError::at_root(
+                "URL \"https://example.com/v1\" has host \"example.com\" but expected \"api.example.com\""
+            )]
+        );
+        assert_eq!(
+            api.json_matches(&json!("https://api.example.com/v10/users")),
+            vec![JsonMatcherError::at_root(
+                "URL \"https://api.example.com/v10/users\" has path \"/v10/users\" outside \"/v1\""
+            )]
+        );
+        assert_eq!(
+            UrlMatcher::new()
+                .host("example.com")
+                .json_matches(&json!("mailto:ada@example.com")),
+            vec![JsonMatcherError::at_root(
+                "URL \"mailto:ada@example.com\" has no host"
+            )]
+        );
+        assert_eq!(
+            UrlMatcher::new().json_matches(&json!(1)),
+            vec![JsonMatcherError::at_root("Expected string for URL")]
+        );
+    }
+}
@@ -0,0 +1,145 @@
+use serde_json::Value;
+
+use crate::{JsonMatcher, JsonMatcherError};
+
+const BASE62: &str = "0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+
+/// Seconds between the unix epoch and the KSUID epoch.
+#[cfg(feature = "datetime")]
+const KSUID_EPOCH: u64 = 1_400_000_000;
+
+/// Matches strings holding a KSUID: 27 base62 characters encoding a 4 byte timestamp and 16 bytes of
+/// payload, e.g. `"0ujtsYcgvSTl8PAuAdqWYSMnLOv"`.
+///
+/// The embedded timestamp can be matched with [`timestamp`](KsuidMatcher::timestamp).
+pub struct KsuidMatcher {
+    #[cfg(feature = "datetime")]
+    timestamp: Option<Box<dyn JsonMatcher>>,
+}
+
+impl Default for KsuidMatcher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl KsuidMatcher {
+    pub fn new() -> Self {
+        Self {
+            #[cfg(feature = "datetime")]
+            timestamp: None,
+        }
+    }
+
+    /// Matches the embedded timestamp, converted to a unix timestamp in milliseconds, against
+    /// `matcher`. KSUID timestamps have a precision of one second.
+    #[cfg(feature = "datetime")]
+    pub fn timestamp(mut self, matcher: impl JsonMatcher + 'static) -> Self {
+        self.timestamp = Some(Box::new(matcher));
+        self
+    }
+}
+
+/// Decodes base62 digits into a 20 byte big-endian number, or returns `None` if it does not fit.
+fn decode_base62(digits: &[u8]) -> Option<[u8; 20]> {
+    let mut bytes = [0_u8; 20];
+    for &digit in digits {
+        let mut carry = digit as u32;
+        for byte in bytes.iter_mut().rev() {
+            let value = *byte as u32 * 62 + carry;
+            *byte = value as u8;
+            carry = value >> 8;
+        }
+        if carry != 0 {
+            return None;
+        }
+    }
+    Some(bytes)
+}
+
+impl JsonMatcher for KsuidMatcher {
+    fn json_matches(&self, value: &Value) -> Vec<JsonMatcherError> {
+        let Some(s) = value.as_str() else {
+            return vec![JsonMatcherError::at_root("Expected string for KSUID")];
+        };
+        if s.chars().count() != 27 {
+            return vec![JsonMatcherError::at_root(format!(
+                "Expected KSUID of 27 characters but got {}",
+                s.chars().count()
+            ))];
+        }
+        let digits = match s
+            .chars()
+            .map(|c| BASE62.find(c).map(|digit| digit as u8).ok_or(c))
+            .collect::<Result<Vec<u8>, char>>()
+        {
+            Ok(digits) => digits,
+            Err(c) => {
+                return vec![JsonMatcherError::at_root(format!(
+                    "KSUID has character `{}` outside base62",
+                    c
+                ))];
+            }
+        };
+        #[cfg_attr(not(feature = "datetime"), allow(unused_variables))]
+        let Some(bytes) = decode_base62(&digits) else {
+            return vec![JsonMatcherError::at_root("KSUID is out of range")];
+        };
+        #[cfg(feature = "datetime")]
+        if let Some(matcher) = &self.timestamp {
+            let seconds = u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+            let millis = (KSUID_EPOCH + seconds as u64) * 1000;
+            return crate::datetime::match_embedded_timestamp(matcher.as_ref(), millis, "KSUID");
+        }
+        vec![]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::assert_jm;
+
+    use super::*;
+
+    #[test]
+    fn test_ksuid_matcher() {
+        let ksuid = json!("0ujtsYcgvSTl8PAuAdqWYSMnLOv");
+        assert_jm!(ksuid, KsuidMatcher::new());
+        assert_jm!(json!("aWgEPTl1tmebfsQzFP4bxwgy80V"), KsuidMatcher::new());
+        assert_eq!(
+            KsuidMatcher::new().json_matches(&json!("aWgEPTl1tmebfsQzFP4bxwgy80W")),
+            vec![JsonMatcherError::at_root("KSUID is out of range")]
+        );
+        assert_eq!(
+            KsuidMatcher::new().json_matches(&json!("0ujtsYcgvSTl8PAuAdqWYSMnLO-")),
+            vec![JsonMatcherError::at_root(
+                "KSUID has character `-` outside base62"
+            )]
+        );
+        assert_eq!(
+            KsuidMatcher::new().json_matches(&json!("0ujtsYcgvSTl8PAuAdqWYSMnLO")),
+            vec![JsonMatcherError::at_root(
+                "Expected KSUID of 27 characters but got 26"
+            )]
+        );
+        assert_eq!(
+            KsuidMatcher::new().json_matches(&json!(null)),
+            vec![JsonMatcherError::at_root("Expected string for KSUID")]
+        );
+    }
+
+    #[cfg(feature = "datetime")]
+    #[test]
+    fn test_ksuid_timestamp() {
+        let ksuid = json!("0ujtsYcgvSTl8PAuAdqWYSMnLOv");
+        assert_jm!(ksuid, KsuidMatcher::new().timestamp(1507608047000_i64));
+        assert_eq!(
+            KsuidMatcher::new().timestamp(0).json_matches(&ksuid),
+            vec![JsonMatcherError::at_root(
+                "KSUID timestamp: Expected integer 0 but got 1507608047000"
+            )]
+        );
+    }
+}
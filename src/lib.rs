@@ -26,7 +26,7 @@
 //!
 //! # Using Matchers
 //!
-//! For flexible matching, use matcher types like [`AnyMatcher`], [`UuidMatcher`], or [`U16Matcher`]:
+//! For flexible matching, use matcher types like [`AnyMatcher`], [`UuidMatcher`], or [`FitsMatcher`]:
 //!
 //! ```
 //! use serde_json::json;
@@ -119,14 +119,39 @@ mod error;
 pub use error::*;
 mod json_matcher;
 pub use json_matcher::*;
+mod assertion;
+pub use assertion::{AssertionContext, SoftAssertions};
+mod report;
+pub use report::{ReportConfig, ReportConfigGuard};
 mod macros;
+mod numeric_policy;
+pub use numeric_policy::{NumericPolicy, NumericPolicyGuard};
+mod scope;
 mod uuid_matcher;
 pub use uuid_matcher::*;
+mod ulid_matcher;
+pub use ulid_matcher::*;
+mod ksuid_matcher;
+pub use ksuid_matcher::*;
+mod nanoid_matcher;
+pub use nanoid_matcher::*;
+mod snowflake_matcher;
+pub use snowflake_matcher::*;
+mod fits_matcher;
+pub use fits_matcher::*;
 mod u16_matcher;
 pub use u16_matcher::*;
+pub mod formats;
 
 #[cfg(feature = "datetime")]
 pub mod datetime;
 
+#[doc(hidden)]
+pub mod __private {
+    pub use crate::assertion::assert_jm;
+    pub use json_matcher_macros::create_json_matcher;
+    pub use serde_json;
+}
+
 #[cfg(test)]
 pub mod test;
@@ -14,139 +14,115 @@
 /// // can also use non-exact "matchers"
 /// assert_jm!(test_data, { "name": "John", "age": AnyMatcher::not_null() })
 /// ```
+///
+/// Like [`assert_eq!`], a custom message can be given after the expectation using [`format!`] syntax.
+/// It is printed alongside the errors, as is any [`AssertionContext`](crate::AssertionContext)
+/// attached at the time:
+///
+/// ```should_panic
+/// use serde_json::json;
+/// use json_matcher::assert_jm;
+///
+/// let (id, user) = (17, "alice");
+/// assert_jm!(json!({ "status": "failed" }), { "status": "created" }, "creating order {} for {}", id, user);
+/// // Output:
+/// // Json matcher failed: creating order 17 for alice
+/// //   - $.status: Expected string "created" but got "failed"
+/// //
+/// // Actual:
+/// // {
+/// //   "status": "failed"
+/// // }
+/// ```
 #[macro_export]
 macro_rules! assert_jm {
     // Handle object syntax directly
-    ($actual:expr, { $($json:tt)* }) => {{
-        let actual = &$actual;
-        let expectation = $crate::create_json_matcher!({ $($json)* });
-        let errors = $crate::JsonMatcher::json_matches(&expectation, &actual);
-        if !errors.is_empty() {
-            let bullets = errors
-                .into_iter()
-                .map(|e| format!("  - {}", e))
-                .collect::<Vec<String>>();
-            let error_message = format!("\nJson matcher failed:\n{}", bullets.join("\n"));
-            let actual_message = format!(
-                "Actual:\n{}",
-                serde_json::to_string_pretty(&actual).unwrap()
-            );
-            panic!("{}\n\n{}", error_message, actual_message);
-        }
-    }};
+    ($actual:expr, { $($json:tt)* } $(, $($arg:tt)+)?) => {
+        $crate::__private::assert_jm(
+            &$actual,
+            &$crate::create_json_matcher!({ $($json)* }),
+            $crate::__jm_message!($($($arg)+)?),
+        )
+    };
 
     // Handle array syntax directly
-    ($actual:expr, [ $($json:tt)* ]) => {{
-        let actual = &$actual;
-        let expectation = $crate::create_json_matcher!([ $($json)* ]);
-        let errors = $crate::JsonMatcher::json_matches(&expectation, &actual);
-        if !errors.is_empty() {
-            let bullets = errors
-                .into_iter()
-                .map(|e| format!("  - {}", e))
-                .collect::<Vec<String>>();
-            let error_message = format!("\nJson matcher failed:\n{}", bullets.join("\n"));
-            let actual_message = format!(
-                "Actual:\n{}",
-                serde_json::to_string_pretty(&actual).unwrap()
-            );
-            panic!("{}\n\n{}", error_message, actual_message);
-        }
-    }};
-
-    // Handle literals directly
-    ($actual:expr, $literal:literal) => {{
-        let actual = &$actual;
-        let expectation = $crate::create_json_matcher!($literal);
-        let errors = $crate::JsonMatcher::json_matches(&expectation, &actual);
-        if !errors.is_empty() {
-            let bullets = errors
-                .into_iter()
-                .map(|e| format!("  - {}", e))
-                .collect::<Vec<String>>();
-            let error_message = format!("\nJson matcher failed:\n{}", bullets.join("\n"));
-            let actual_message = format!(
-                "Actual:\n{}",
-                serde_json::to_string_pretty(&actual).unwrap()
-            );
-            panic!("{}\n\n{}", error_message, actual_message);
-        }
-    }};
+    ($actual:expr, [ $($json:tt)* ] $(, $($arg:tt)+)?) => {
+        $crate::__private::assert_jm(
+            &$actual,
+            &$crate::create_json_matcher!([ $($json)* ]),
+            $crate::__jm_message!($($($arg)+)?),
+        )
+    };
 
     // Handle null
-    ($actual:expr, null) => {{
-        let actual = &$actual;
-        let expectation = $crate::create_json_matcher!(null);
-        let errors = $crate::JsonMatcher::json_matches(&expectation, &actual);
-        if !errors.is_empty() {
-            let bullets = errors
-                .into_iter()
-                .map(|e| format!("  - {}", e))
-                .collect::<Vec<String>>();
-            let error_message = format!("\nJson matcher failed:\n{}", bullets.join("\n"));
-            let actual_message = format!(
-                "Actual:\n{}",
-                serde_json::to_string_pretty(&actual).unwrap()
-            );
-            panic!("{}\n\n{}", error_message, actual_message);
-        }
-    }};
-
-    // Handle true
-    ($actual:expr, true) => {{
-        let actual = &$actual;
-        let expectation = $crate::create_json_matcher!(true);
-        let errors = $crate::JsonMatcher::json_matches(&expectation, &actual);
-        if !errors.is_empty() {
-            let bullets = errors
-                .into_iter()
-                .map(|e| format!("  - {}", e))
-                .collect::<Vec<String>>();
-            let error_message = format!("\nJson matcher failed:\n{}", bullets.join("\n"));
-            let actual_message = format!(
-                "Actual:\n{}",
-                serde_json::to_string_pretty(&actual).unwrap()
-            );
-            panic!("{}\n\n{}", error_message, actual_message);
-        }
-    }};
+    ($actual:expr, null $(, $($arg:tt)+)?) => {
+        $crate::__private::assert_jm(
+            &$actual,
+            &$crate::create_json_matcher!(null),
+            $crate::__jm_message!($($($arg)+)?),
+        )
+    };
 
-    // Handle false
-    ($actual:expr, false) => {{
-        let actual = &$actual;
-        let expectation = $crate::create_json_matcher!(false);
-        let errors = $crate::JsonMatcher::json_matches(&expectation, &actual);
-        if !errors.is_empty() {
-            let bullets = errors
-                .into_iter()
-                .map(|e| format!("  - {}", e))
-                .collect::<Vec<String>>();
-            let error_message = format!("\nJson matcher failed:\n{}", bullets.join("\n"));
-            let actual_message = format!(
-                "Actual:\n{}",
-                serde_json::to_string_pretty(&actual).unwrap()
-            );
-            panic!("{}\n\n{}", error_message, actual_message);
-        }
-    }};
+    // Handle literals directly, including true and false
+    ($actual:expr, $literal:literal $(, $($arg:tt)+)?) => {
+        $crate::__private::assert_jm(
+            &$actual,
+            &$crate::create_json_matcher!($literal),
+            $crate::__jm_message!($($($arg)+)?),
+        )
+    };
 
     // Original syntax - when passed an expression (must be last)
-    ($actual:expr, $expectation:expr) => {{
-        let actual = &$actual;
-        let expectation = &$expectation;
-        let errors = $crate::JsonMatcher::json_matches(expectation, &actual);
-        if !errors.is_empty() {
-            let bullets = errors
-                .into_iter()
-                .map(|e| format!("  - {}", e))
-                .collect::<Vec<String>>();
-            let error_message = format!("\nJson matcher failed:\n{}", bullets.join("\n"));
-            let actual_message = format!(
-                "Actual:\n{}",
-                serde_json::to_string_pretty(&actual).unwrap()
-            );
-            panic!("{}\n\n{}", error_message, actual_message);
-        }
+    ($actual:expr, $expectation:expr $(, $($arg:tt)+)?) => {
+        $crate::__private::assert_jm(
+            &$actual,
+            &$expectation,
+            $crate::__jm_message!($($($arg)+)?),
+        )
+    };
+}
+
+/// Turns the optional trailing format arguments of [`assert_jm!`] into an `Option<fmt::Arguments>`.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __jm_message {
+    () => {
+        ::core::option::Option::None
+    };
+    ($($arg:tt)+) => {
+        ::core::option::Option::Some(::core::format_args!($($arg)+))
+    };
+}
+
+/// Runs the given statements in a [`SoftAssertions`](crate::SoftAssertions) scope: every
+/// [`assert_jm!`] inside records its failure instead of panicking, and all failures are reported
+/// together at the end of the block. Evaluates to the value of the block.
+///
+/// ```should_panic
+/// use serde_json::json;
+/// use json_matcher::{assert_jm, jm_soft};
+///
+/// let responses = vec![json!({ "ok": true }), json!({ "ok": false }), json!({ "ok": false })];
+/// jm_soft! {
+///     for response in &responses {
+///         assert_jm!(response, { "ok": true });
+///     }
+/// }
+/// // Output:
+/// // Soft json matcher assertions failed (2 of 3):
+/// //
+/// // --- src/main.rs:8:9 ---
+/// // Json matcher failed:
+/// //   - $.ok: Value is not true
+/// // ...
+/// ```
+#[macro_export]
+macro_rules! jm_soft {
+    ($($body:tt)*) => {{
+        let soft = $crate::SoftAssertions::new();
+        let result = { $($body)* };
+        soft.finish();
+        result
     }};
 }
 
@@ -179,138 +155,52 @@ macro_rules! assert_jm {
 ///     }]
 /// );
 /// ```
+///
+/// Object keys may be string literals, identifiers, or parenthesized expressions evaluating to
+/// something that can be borrowed as a `&str`:
+///
+/// ```
+/// use json_matcher::{assert_jm, create_json_matcher};
+/// use serde_json::json;
+///
+/// let key = format!("user_{}", 7);
+/// let matcher = create_json_matcher!({
+///     (key): { name: "John" }
+/// });
+///
+/// assert_jm!(json!({ "user_7": { "name": "John" } }), matcher);
+/// ```
+///
+/// Syntax mistakes are reported at the offending token:
+///
+/// ```compile_fail
+/// use json_matcher::create_json_matcher;
+///
+/// // error: expected `,`
+/// let matcher = create_json_matcher!({
+///     "name": "John"
+///     "age": 30
+/// });
+/// ```
+///
+/// ```compile_fail
+/// use json_matcher::create_json_matcher;
+///
+/// // error: expected a value after `:` in object field
+/// let matcher = create_json_matcher!({
+///     "name":
+/// });
+/// ```
 #[macro_export]
 macro_rules! create_json_matcher {
-    // Handle null
-    (null) => {
-        $crate::NullMatcher::new()
-    };
-
-    // Handle booleans
-    (true) => {
-        $crate::BooleanMatcher::exact(true)
-    };
-    (false) => {
-        $crate::BooleanMatcher::exact(false)
-    };
-
-    // Handle numbers (integers and floats)
-    ($num:literal) => {{
-        // We'll use serde_json::json! to parse the number and then convert
-        let value = serde_json::json!($num);
-        value
-    }};
-
-    // Handle strings
-    ($string:literal) => {
-        $crate::StringMatcher::new($string)
-    };
-
-    // Handle arrays
-    ([ $($item:tt),* $(,)? ]) => {
-        $crate::ArrayMatcher::new()
-            $(.element($crate::create_json_matcher!($item)))*
-    };
-
-    // Handle objects
-    ({ $($json:tt)* }) => {
-        $crate::create_json_matcher!(@object {} $($json)*)
-    };
-
-    // Internal rules for parsing object fields
-    // Handle empty object (no fields)
-    (@object {$($out:tt)*}) => {
-        $crate::ObjectMatcher::new() $($out)*
-    };
-    // Handle nested objects
-    (@object {$($out:tt)*} $key:literal : { $($value:tt)* } , $($rest:tt)*) => {
-        $crate::create_json_matcher!(@object {$($out)* .field($key, $crate::create_json_matcher!({ $($value)* }))} $($rest)*)
-    };
-    (@object {$($out:tt)*} $key:literal : { $($value:tt)* }) => {
-        $crate::ObjectMatcher::new() $($out)* .field($key, $crate::create_json_matcher!({ $($value)* }))
-    };
-    // Handle arrays
-    (@object {$($out:tt)*} $key:literal : [ $($value:tt)* ] , $($rest:tt)*) => {
-        $crate::create_json_matcher!(@object {$($out)* .field($key, $crate::create_json_matcher!([ $($value)* ]))} $($rest)*)
-    };
-    (@object {$($out:tt)*} $key:literal : [ $($value:tt)* ]) => {
-        $crate::ObjectMatcher::new() $($out)* .field($key, $crate::create_json_matcher!([ $($value)* ]))
-    };
-    // Handle null, true, false keywords (must come before literals)
-    (@object {$($out:tt)*} $key:literal : null , $($rest:tt)*) => {
-        $crate::create_json_matcher!(@object {$($out)* .field($key, $crate::create_json_matcher!(null))} $($rest)*)
-    };
-    (@object {$($out:tt)*} $key:literal : null) => {
-        $crate::ObjectMatcher::new() $($out)* .field($key, $crate::create_json_matcher!(null))
-    };
-    (@object {$($out:tt)*} $key:literal : true , $($rest:tt)*) => {
-        $crate::create_json_matcher!(@object {$($out)* .field($key, $crate::create_json_matcher!(true))} $($rest)*)
-    };
-    (@object {$($out:tt)*} $key:literal : true) => {
-        $crate::ObjectMatcher::new() $($out)* .field($key, $crate::create_json_matcher!(true))
-    };
-    (@object {$($out:tt)*} $key:literal : false , $($rest:tt)*) => {
-        $crate::create_json_matcher!(@object {$($out)* .field($key, $crate::create_json_matcher!(false))} $($rest)*)
-    };
-    (@object {$($out:tt)*} $key:literal : false) => {
-        $crate::ObjectMatcher::new() $($out)* .field($key, $crate::create_json_matcher!(false))
-    };
-    // Handle literals
-    (@object {$($out:tt)*} $key:literal : $value:literal , $($rest:tt)*) => {
-        $crate::create_json_matcher!(@object {$($out)* .field($key, $crate::create_json_matcher!($value))} $($rest)*)
-    };
-    (@object {$($out:tt)*} $key:literal : $value:literal) => {
-        $crate::ObjectMatcher::new() $($out)* .field($key, $crate::create_json_matcher!($value))
-    };
-    // Handle identifiers as keys with null, true, false
-    (@object {$($out:tt)*} $key:ident : null , $($rest:tt)*) => {
-        $crate::create_json_matcher!(@object {$($out)* .field(stringify!($key), $crate::create_json_matcher!(null))} $($rest)*)
-    };
-    (@object {$($out:tt)*} $key:ident : null) => {
-        $crate::ObjectMatcher::new() $($out)* .field(stringify!($key), $crate::create_json_matcher!(null))
-    };
-    (@object {$($out:tt)*} $key:ident : true , $($rest:tt)*) => {
-        $crate::create_json_matcher!(@object {$($out)* .field(stringify!($key), $crate::create_json_matcher!(true))} $($rest)*)
-    };
-    (@object {$($out:tt)*} $key:ident : true) => {
-        $crate::ObjectMatcher::new() $($out)* .field(stringify!($key), $crate::create_json_matcher!(true))
-    };
-    (@object {$($out:tt)*} $key:ident : false , $($rest:tt)*) => {
-        $crate::create_json_matcher!(@object {$($out)* .field(stringify!($key), $crate::create_json_matcher!(false))} $($rest)*)
-    };
-    (@object {$($out:tt)*} $key:ident : false) => {
-        $crate::ObjectMatcher::new() $($out)* .field(stringify!($key), $crate::create_json_matcher!(false))
-    };
-    // Handle identifiers as keys with literal values
-    (@object {$($out:tt)*} $key:ident : $value:literal , $($rest:tt)*) => {
-        $crate::create_json_matcher!(@object {$($out)* .field(stringify!($key), $crate::create_json_matcher!($value))} $($rest)*)
-    };
-    (@object {$($out:tt)*} $key:ident : $value:literal) => {
-        $crate::ObjectMatcher::new() $($out)* .field(stringify!($key), $crate::create_json_matcher!($value))
-    };
-    // Handle expressions (matchers, variables, etc.) - must come last as catch-all
-    (@object {$($out:tt)*} $key:literal : $value:expr , $($rest:tt)*) => {
-        $crate::create_json_matcher!(@object {$($out)* .field($key, $value)} $($rest)*)
-    };
-    (@object {$($out:tt)*} $key:literal : $value:expr) => {
-        $crate::ObjectMatcher::new() $($out)* .field($key, $value)
-    };
-    (@object {$($out:tt)*} $key:ident : $value:expr , $($rest:tt)*) => {
-        $crate::create_json_matcher!(@object {$($out)* .field(stringify!($key), $value)} $($rest)*)
-    };
-    (@object {$($out:tt)*} $key:ident : $value:expr) => {
-        $crate::ObjectMatcher::new() $($out)* .field(stringify!($key), $value)
-    };
-
-    // Handle expressions (for matcher types) - this must come last
-    ($expr:expr) => {
-        $expr
+    ($($json:tt)*) => {
+        $crate::__private::create_json_matcher!($crate; $($json)*)
     };
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{assert_jm, create_json_matcher, test::catch_string_panic};
+    use crate::test::catch_string_panic;
     use crate::{AnyMatcher, JsonMatcher};
     use serde_json::json;
 
@@ -503,6 +393,67 @@ Actual:
         assert!(error_message.contains("Expected string \"John\" but got \"Jane\""));
     }
 
+    #[test]
+    fn test_assert_jm_custom_message() {
+        let (id, user) = (17, "alice");
+        assert_jm!(json!({"id": 17}), { "id": 17 }, "creating order {} for {}", id, user);
+        assert_eq!(
+            catch_string_panic(|| assert_jm!(
+                json!({"id": 18}),
+                { "id": 17 },
+                "creating order {} for {}",
+                id,
+                user
+            )),
+            r#"
+Json matcher failed: creating order 17 for alice
+  - $.id: Expected integer 17 but got 18
+
+Actual:
+{
+  "id": 18
+}"#
+        );
+        assert_eq!(
+            catch_string_panic(|| assert_jm!(json!([1]), [2], "array {id}")),
+            r#"
+Json matcher failed: array 17
+  - $.0: Expected integer 2 but got 1
+
+Actual:
+[
+  1
+]"#
+        );
+        assert_eq!(
+            catch_string_panic(|| assert_jm!(json!(1), null, "null")),
+            r#"
+Json matcher failed: null
+  - $: Value is not null
+
+Actual:
+1"#
+        );
+        assert_eq!(
+            catch_string_panic(|| assert_jm!(json!(1), true, "literal")),
+            r#"
+Json matcher failed: literal
+  - $: Value is not a boolean
+
+Actual:
+1"#
+        );
+        assert_eq!(
+            catch_string_panic(|| assert_jm!(json!(null), AnyMatcher::not_null(), "expression")),
+            r#"
+Json matcher failed: expression
+  - $: Expected non-null value
+
+Actual:
+null"#
+        );
+    }
+
     #[test]
     fn test_create_json_matcher_macro_directly() {
         let matcher = create_json_matcher!({
@@ -600,4 +551,27 @@ Actual:
         // Test array with empty object
         assert_jm!(json!([{}]), [{}]);
     }
+
+    #[test]
+    fn test_create_json_matcher_keys_and_expressions() {
+        let key = String::from("computed");
+        let matcher = create_json_matcher!({
+            "literal": 1,
+            ident: -2,
+            r#type: null,
+            (key): [AnyMatcher::not_null(), { "nested": 3 }],
+            ("static"): 1.5
+        });
+
+        assert_eq!(
+            matcher.json_matches(&json!({
+                "literal": 1,
+                "ident": -2,
+                "type": null,
+                "computed": ["anything", { "nested": 3 }],
+                "static": 1.5
+            })),
+            vec![]
+        );
+    }
 }
@@ -0,0 +1,551 @@
+use std::cmp::Ordering;
+use std::fmt::{self, Display};
+
+use serde_json::{Number, Value};
+
+use crate::{JsonMatcher, JsonMatcherError};
+
+/// The most digits a value is padded to when aligning it with another, unless the values
+/// themselves have more. Values further apart in scale, like `1e-999999999` and `1`, cannot be
+/// added or divided exactly.
+const MAX_ALIGNED_DIGITS: usize = 1000;
+
+/// An exact decimal number, normalized so that equal values compare equal regardless of how they
+/// were written, e.g. `12.50`, `12.5` and `1.25e1`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Decimal {
+    negative: bool,
+    /// Significant digits without leading or trailing zeros, empty for zero.
+    digits: String,
+    /// The value is `digits * 10^exponent`.
+    exponent: i64,
+}
+
+impl Decimal {
+    /// Parses `[+-]digits[.digits][e[+-]digits]`, allowing leading zeros.
+    pub(crate) fn parse(s: &str) -> Option<Self> {
+        let (negative, s) = match s.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, s.strip_prefix('+').unwrap_or(s)),
+        };
+        let (mantissa, exponent) = match s.find(['e', 'E']) {
+            Some(i) => {
+                let exponent = &s[i + 1..];
+                let unsigned = exponent.strip_prefix(['+', '-']).unwrap_or(exponent);
+                if unsigned.is_empty() || !unsigned.bytes().all(|b| b.is_ascii_digit()) {
+                    return None;
+                }
+                (&s[..i], exponent.parse::<i64>().ok()?)
+            }
+            None => (s, 0),
+        };
+        let (integer, fraction) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+        if integer.is_empty()
+            || (mantissa.contains('.') && fraction.is_empty())
+            || !integer
+                .bytes()
+                .chain(fraction.bytes())
+                .all(|b| b.is_ascii_digit())
+        {
+            return None;
+        }
+        let digits = format!("{}{}", integer, fraction);
+        let exponent = exponent.checked_sub(fraction.len() as i64)?;
+        Some(Self::normalize(negative, &digits, exponent))
+    }
+
+    fn normalize(negative: bool, digits: &str, exponent: i64) -> Self {
+        let leading = digits.trim_start_matches('0');
+        let trimmed = leading.trim_end_matches('0');
+        if trimmed.is_empty() {
+            return Self {
+                negative: false,
+                digits: String::new(),
+                exponent: 0,
+            };
+        }
+        Self {
+            negative,
+            digits: trimmed.to_string(),
+            exponent: exponent.saturating_add((leading.len() - trimmed.len()) as i64),
+        }
+    }
+
+    /// The exact value of a JSON number, as written when serde_json's `arbitrary_precision`
+    /// feature is enabled. Fails for numbers whose exponent does not fit an `i64`, which only
+    /// that feature lets through.
+    pub(crate) fn from_number(num: &Number) -> Result<Self, JsonMatcherError> {
+        Self::parse(&num.to_string()).ok_or_else(|| {
+            JsonMatcherError::at_root(format!("Number {} is out of the supported range", num))
+        })
+    }
+
+    /// The shortest decimal that round-trips to `value`, or `None` if it is not finite.
+    pub(crate) fn from_f64(value: f64) -> Option<Self> {
+        match value.is_finite() {
+            true => Self::parse(&format!("{:e}", value)),
+            false => None,
+        }
+    }
+
+    pub(crate) fn is_zero(&self) -> bool {
+        self.digits.is_empty()
+    }
+
+    pub(crate) fn is_integer(&self) -> bool {
+        self.exponent >= 0
+    }
+
+    /// The number of digits after the decimal point, ignoring trailing zeros.
+    pub(crate) fn decimal_places(&self) -> u64 {
+        match self.exponent < 0 {
+            true => self.exponent.unsigned_abs(),
+            false => 0,
+        }
+    }
+
+    /// The number of digits before the decimal point, ignoring leading zeros.
+    pub(crate) fn integer_digits(&self) -> u64 {
+        (self.digits.len() as i64)
+            .saturating_add(self.exponent)
+            .max(0) as u64
+    }
+
+    /// Whether the value is an even integer.
+    pub(crate) fn is_even(&self) -> bool {
+        match self.digits.as_bytes().last() {
+            Some(last) if self.exponent == 0 => matches!(last, b'0' | b'2' | b'4' | b'6' | b'8'),
+            _ => self.exponent > 0 || self.is_zero(),
+        }
+    }
+
+    pub(crate) fn abs(&self) -> Self {
+        Self {
+            negative: false,
+            ..self.clone()
+        }
+    }
+
+    fn neg(&self) -> Self {
+        Self {
+            negative: !self.negative && !self.is_zero(),
+            ..self.clone()
+        }
+    }
+
+    /// The exact sum, or `None` if the values are too far apart in scale to align.
+    pub(crate) fn add(&self, other: &Self) -> Option<Self> {
+        let (a, b, exponent) = self.aligned(other)?;
+        let (negative, digits) = if self.negative == other.negative {
+            (self.negative, add_digits(&a, &b))
+        } else if cmp_digits(&a, &b) == Ordering::Less {
+            (other.negative, sub_digits(&b, &a))
+        } else {
+            (self.negative, sub_digits(&a, &b))
+        };
+        let digits: String = digits.iter().map(|d| (b'0' + d) as char).collect();
+        Some(Self::normalize(negative, &digits, exponent))
+    }
+
+    /// The exact difference, or `None` if the values are too far apart in scale to align.
+    pub(crate) fn sub(&self, other: &Self) -> Option<Self> {
+        self.add(&other.neg())
+    }
+
+    /// Whether the value is an integer multiple of `step`, which must not be zero, or `None` if
+    /// the values are too far apart in scale to align.
+    pub(crate) fn is_multiple_of(&self, step: &Self) -> Option<bool> {
+        let (a, b, _) = self.aligned(step)?;
+        let mut remainder = vec![];
+        for digit in a {
+            remainder.push(digit);
+            while cmp_digits(&remainder, &b) != Ordering::Less {
+                remainder = sub_digits(&remainder, &b);
+            }
+        }
+        Some(remainder.iter().all(|&d| d == 0))
+    }
+
+    /// The magnitudes of both values as integers of digits, scaled to their common exponent, or
+    /// `None` if that takes more than [`MAX_ALIGNED_DIGITS`] digits or the digits of both values.
+    fn aligned(&self, other: &Self) -> Option<(Vec<u8>, Vec<u8>, i64)> {
+        let exponent = self.exponent.min(other.exponent);
+        let limit = MAX_ALIGNED_DIGITS.max(self.digits.len() + other.digits.len());
+        let scale = |d: &Self| {
+            let padding = usize::try_from(d.exponent.abs_diff(exponent)).ok()?;
+            let len = d
+                .digits
+                .len()
+                .checked_add(padding)
+                .filter(|&len| len <= limit)?;
+            let mut digits: Vec<u8> = d.digits.bytes().map(|b| b - b'0').collect();
+            digits.resize(len, 0);
+            Some(digits)
+        };
+        Some((scale(self)?, scale(other)?, exponent))
+    }
+
+    fn cmp_magnitude(&self, other: &Self) -> Ordering {
+        match (self.is_zero(), other.is_zero()) {
+            (true, true) => Ordering::Equal,
+            (true, false) => Ordering::Less,
+            (false, true) => Ordering::Greater,
+            (false, false) => {
+                let magnitude = |d: &Self| (d.digits.len() as i64).saturating_add(d.exponent);
+                magnitude(self)
+                    .cmp(&magnitude(other))
+                    .then_with(|| self.digits.cmp(&other.digits))
+            }
+        }
+    }
+}
+
+impl From<i128> for Decimal {
+    fn from(value: i128) -> Self {
+        Self::normalize(value < 0, &value.unsigned_abs().to_string(), 0)
+    }
+}
+
+impl From<u128> for Decimal {
+    fn from(value: u128) -> Self {
+        Self::normalize(false, &value.to_string(), 0)
+    }
+}
+
+impl Ord for Decimal {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.negative, other.negative) {
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+            (false, false) => self.cmp_magnitude(other),
+            (true, true) => other.cmp_magnitude(self),
+        }
+    }
+}
+
+impl PartialOrd for Decimal {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Display for Decimal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_zero() {
+            return write!(f, "0");
+        }
+        if self.negative {
+            write!(f, "-")?;
+        }
+        let point = (self.digits.len() as i64).saturating_add(self.exponent);
+        if self.exponent > 64 || point < -64 {
+            // avoid printing huge runs of zeros
+            let (first, rest) = self.digits.split_at(1);
+            return match rest.is_empty() {
+                true => write!(f, "{}e{}", first, point - 1),
+                false => write!(f, "{}.{}e{}", first, rest, point - 1),
+            };
+        }
+        if self.exponent >= 0 {
+            write!(f, "{}{}", self.digits, "0".repeat(self.exponent as usize))
+        } else if point > 0 {
+            let (integer, fraction) = self.digits.split_at(point as usize);
+            write!(f, "{}.{}", integer, fraction)
+        } else {
+            write!(
+                f,
+                "0.{}{}",
+                "0".repeat(point.unsigned_abs() as usize),
+                self.digits
+            )
+        }
+    }
+}
+
+fn strip_leading_zeros(digits: &[u8]) -> &[u8] {
+    let start = digits.iter().position(|&d| d != 0).unwrap_or(digits.len());
+    &digits[start..]
+}
+
+fn cmp_digits(a: &[u8], b: &[u8]) -> Ordering {
+    let (a, b) = (strip_leading_zeros(a), strip_leading_zeros(b));
+    a.len().cmp(&b.len()).then_with(|| a.cmp(b))
+}
+
+fn add_digits(a: &[u8], b: &[u8]) -> Vec<u8> {
+    let mut sum = vec![];
+    let mut carry = 0;
+    for i in 0..a.len().max(b.len()) {
+        let digit = |x: &[u8]| if i < x.len() { x[x.len() - 1 - i] } else { 0 };
+        let total = digit(a) + digit(b) + carry;
+        sum.push(total % 10);
+        carry = total / 10;
+    }
+    sum.push(carry);
+    sum.reverse();
+    sum
+}
+
+/// Subtracts `b` from `a`, which must not be smaller.
+fn sub_digits(a: &[u8], b: &[u8]) -> Vec<u8> {
+    let mut difference = vec![];
+    let mut borrow = 0;
+    for i in 0..a.len() {
+        let subtrahend = if i < b.len() { b[b.len() - 1 - i] } else { 0 } + borrow;
+        let minuend = a[a.len() - 1 - i];
+        borrow = u8::from(minuend < subtrahend);
+        difference.push(minuend + borrow * 10 - subtrahend);
+    }
+    difference.reverse();
+    difference
+}
+
+/// Whether a JSON number was written as a float, i.e. with a fraction or an exponent.
+pub(crate) fn is_float(num: &Number) -> bool {
+    num.to_string().contains(['.', 'e', 'E'])
+}
+
+/// Matches numbers by their exact decimal value, optionally checking that they fit a SQL-style
+/// `NUMERIC(precision, scale)` column.
+///
+/// JSON numbers are only kept exactly as written with this crate's `arbitrary_precision` feature,
+/// which enables serde_json's feature of the same name. Without it, non-integers are parsed into an
+/// `f64` before they reach the matcher and may already have lost precision.
+pub struct DecimalMatcher {
+    value: Option<Decimal>,
+    scale: Option<u64>,
+    precision: Option<u64>,
+}
+
+impl DecimalMatcher {
+    /// Matches numbers equal to `value`, e.g. `"12.50"`, which also matches `12.5` and `1250e-2`.
+    ///
+    /// Panics if `value` is not a decimal number.
+    pub fn new(value: &str) -> Self {
+        let Some(value) = Decimal::parse(value) else {
+            panic!("Invalid decimal number {:?}", value);
+        };
+        Self {
+            value: Some(value),
+            scale: None,
+            precision: None,
+        }
+    }
+
+    /// Matches any number, subject to [`scale`](DecimalMatcher::scale) and
+    /// [`precision`](DecimalMatcher::precision).
+    pub fn any() -> Self {
+        Self {
+            value: None,
+            scale: None,
+            precision: None,
+        }
+    }
+
+    /// Requires at most `scale` digits after the decimal point, ignoring trailing zeros.
+    pub fn scale(mut self, scale: u64) -> Self {
+        self.scale = Some(scale);
+        self
+    }
+
+    /// Requires at most `precision` significant digits in total. Combined with
+    /// [`scale`](DecimalMatcher::scale), at most `precision - scale` digits may come before the
+    /// decimal point.
+    pub fn precision(mut self, precision: u64) -> Self {
+        self.precision = Some(precision);
+        self
+    }
+}
+
+impl JsonMatcher for DecimalMatcher {
+    fn json_matches(&self, value: &Value) -> Vec<JsonMatcherError> {
+        let Value::Number(num) = value else {
+            return vec![JsonMatcherError::at_root("Value is not a decimal")];
+        };
+        let actual = match Decimal::from_number(num) {
+            Ok(actual) => actual,
+            Err(error) => return vec![error],
+        };
+        let mut errors = vec![];
+        if let Some(expected) = &self.value {
+            if actual != *expected {
+                errors.push(JsonMatcherError::at_root(format!(
+                    "Expected decimal {} but got {}",
+                    expected, num
+                )));
+            }
+        }
+        if let Some(scale) = self.scale {
+            if actual.decimal_places() > scale {
+                errors.push(JsonMatcherError::at_root(format!(
+                    "Decimal {} has {} decimal places but scale is {}",
+                    num,
+                    actual.decimal_places(),
+                    scale
+                )));
+            }
+        }
+        if let Some(precision) = self.precision {
+            match self.scale {
+                Some(scale) if actual.integer_digits() + scale > precision => {
+                    errors.push(JsonMatcherError::at_root(format!(
+                        "Decimal {} has {} integer digits but precision {} with scale {} allows {}",
+                        num,
+                        actual.integer_digits(),
+                        precision,
+                        scale,
+                        precision.saturating_sub(scale)
+                    )));
+                }
+                None if actual.integer_digits() + actual.decimal_places() > precision => {
+                    errors.push(JsonMatcherError::at_root(format!(
+                        "Decimal {} has {} digits but precision is {}",
+                        num,
+                        actual.integer_digits() + actual.decimal_places(),
+                        precision
+                    )));
+                }
+                _ => {}
+            }
+        }
+        errors
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::assert_jm;
+
+    use super::*;
+
+    #[test]
+    fn test_decimal() {
+        let parse = |s: &str| Decimal::parse(s).unwrap();
+        assert_eq!(parse("12.50"), parse("1.25e1"));
+        assert_eq!(parse("0012.5"), parse("+1250E-2"));
+        assert_eq!(parse("-0.0"), parse("0"));
+        assert_eq!(parse("100"), Decimal::from(100_i128));
+        assert_eq!(Decimal::from_f64(0.1), Some(parse("0.1")));
+        assert_eq!(Decimal::from_f64(f64::NAN), None);
+        for invalid in ["", "-", ".5", "5.", "1e", "1e+", "0x10", "1_000", " 1"] {
+            assert_eq!(Decimal::parse(invalid), None, "{}", invalid);
+        }
+        assert!(parse("-10") < parse("-9.99"));
+        assert!(parse("-0.5") < parse("0"));
+        assert!(parse("0.123") < parse("0.13"));
+        assert!(parse("99") < parse("1e2"));
+        assert_eq!(parse("12.50").to_string(), "12.5");
+        assert_eq!(parse("-0.00125").to_string(), "-0.00125");
+        assert_eq!(parse("12e3").to_string(), "12000");
+        assert_eq!(parse("1.5e100").to_string(), "1.5e100");
+        assert_eq!(parse("12.345").decimal_places(), 3);
+        assert_eq!(parse("12.345").integer_digits(), 2);
+        assert_eq!(parse("0.05").integer_digits(), 0);
+        assert_eq!(parse("12.5").sub(&parse("0.75")), Some(parse("11.75")));
+        assert_eq!(parse("0.75").sub(&parse("12.5")), Some(parse("-11.75")));
+        assert_eq!(parse("-1.5").add(&parse("1.5")), Some(parse("0")));
+        assert_eq!(parse("999").add(&parse("1e-3")), Some(parse("999.001")));
+        assert_eq!(parse("12.30").is_multiple_of(&parse("0.01")), Some(true));
+        assert_eq!(parse("-1e20").is_multiple_of(&parse("25")), Some(true));
+        assert_eq!(parse("12.305").is_multiple_of(&parse("0.01")), Some(false));
+        assert_eq!(parse("7").is_multiple_of(&parse("1.5")), Some(false));
+        assert_eq!(parse("1.5e1").is_multiple_of(&parse("7.5")), Some(true));
+        // values too far apart in scale are not expanded
+        assert_eq!(parse("1").sub(&parse("1e-999999999")), None);
+        assert_eq!(parse("1e999999999").is_multiple_of(&parse("3")), None);
+        assert_eq!(
+            parse("1").sub(&parse("1e-999")),
+            Some(parse(&format!("0.{}", "9".repeat(999))))
+        );
+        assert!(parse("12").is_even() && parse("1e3").is_even() && parse("0").is_even());
+        assert!(!parse("-7").is_even());
+    }
+
+    #[test]
+    fn test_decimal_matcher() {
+        assert_jm!(json!(12.5), DecimalMatcher::new("12.50"));
+        assert_jm!(json!(0.1), DecimalMatcher::new("0.1"));
+        assert_jm!(json!(1250), DecimalMatcher::new("1.25e3"));
+        assert_eq!(
+            DecimalMatcher::new("12.50").json_matches(&json!(12.51)),
+            vec![JsonMatcherError::at_root(
+                "Expected decimal 12.5 but got 12.51"
+            )]
+        );
+        assert_eq!(
+            DecimalMatcher::new("12.50").json_matches(&json!("12.50")),
+            vec![JsonMatcherError::at_root("Value is not a decimal")]
+        );
+
+        let money = DecimalMatcher::any().precision(5).scale(2);
+        assert_jm!(json!(999.99), money);
+        assert_jm!(json!(-5), money);
+        assert_eq!(
+            money.json_matches(&json!(1.005)),
+            vec![JsonMatcherError::at_root(
+                "Decimal 1.005 has 3 decimal places but scale is 2"
+            )]
+        );
+        assert_eq!(
+            money.json_matches(&json!(1000)),
+            vec![JsonMatcherError::at_root(
+                "Decimal 1000 has 4 integer digits but precision 5 with scale 2 allows 3"
+            )]
+        );
+        assert_eq!(
+            DecimalMatcher::any()
+                .precision(3)
+                .json_matches(&json!(12.34)),
+            vec![JsonMatcherError::at_root(
+                "Decimal 12.34 has 4 digits but precision is 3"
+            )]
+        );
+    }
+
+    #[cfg(feature = "arbitrary_precision")]
+    #[test]
+    fn test_decimal_matcher_arbitrary_precision() {
+        let value: Value = serde_json::from_str("12345678901234567890.000000001").unwrap();
+        assert_jm!(value, DecimalMatcher::new("12345678901234567890.000000001"));
+        assert_eq!(
+            DecimalMatcher::new("12345678901234567890.000000002").json_matches(&value),
+            vec![JsonMatcherError::at_root(
+                "Expected decimal 12345678901234567890.000000002 but got 12345678901234567890.000000001"
+            )]
+        );
+        let value: Value = serde_json::from_str("12.50").unwrap();
+        assert_jm!(value, DecimalMatcher::any().scale(2));
+        // integers and floats are exact beyond the range of u64 and f64 too
+        let value: Value = serde_json::from_str("170141183460469231731687303715884105727").unwrap();
+        assert_jm!(value, i128::MAX);
+        assert_jm!(value, value.clone());
+        let value: Value = serde_json::from_str("0.10000000000000000001").unwrap();
+        assert_eq!(
+            0.1.json_matches(&value),
+            vec![JsonMatcherError::at_root(
+                "Expected float 0.1 but got 0.10000000000000000001"
+            )]
+        );
+        assert_jm!(value, value.clone());
+    }
+
+    #[cfg(feature = "arbitrary_precision")]
+    #[test]
+    fn test_numbers_out_of_range() {
+        use crate::{FitsMatcher, IntegerMatcher, NumberMatcher, NumericMatcher};
+
+        let value: Value = serde_json::from_str("1e99999999999999999999").unwrap();
+        let error = vec![JsonMatcherError::at_root(
+            "Number 1e99999999999999999999 is out of the supported range",
+        )];
+        assert_eq!(IntegerMatcher::new(1).json_matches(&value), error);
+        assert_eq!(NumberMatcher::new(1.0).json_matches(&value), error);
+        assert_eq!(FitsMatcher::<u64>::new().json_matches(&value), error);
+        assert_eq!(NumericMatcher::new().min(0).json_matches(&value), error);
+        assert_eq!(DecimalMatcher::any().json_matches(&value), error);
+        assert_eq!(1.5.json_matches(&value), error);
+        assert_eq!(json!(1).json_matches(&value), error);
+    }
+}
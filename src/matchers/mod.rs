@@ -1,5 +1,11 @@
 mod number;
 pub use number::*;
+mod decimal;
+pub use decimal::*;
+mod numeric;
+pub use numeric::*;
+mod numeric_string;
+pub use numeric_string::*;
 mod array;
 pub use array::*;
 mod object;
@@ -11,5 +17,7 @@ mod value;
 pub use boolean::*;
 mod string;
 pub use string::*;
+mod one_of;
+pub use one_of::*;
 mod any;
 pub use any::*;
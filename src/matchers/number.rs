@@ -1,14 +1,33 @@
-use serde_json::Value;
+use serde_json::{Number, Value};
 
-use crate::{JsonMatcher, JsonMatcherError};
+use crate::matchers::decimal::{is_float, Decimal};
+use crate::{JsonMatcher, JsonMatcherError, NumericPolicy};
+
+/// Returns the value of an integer JSON number, covering the full `i64` and `u64` ranges.
+pub(crate) fn integer_value(num: &Number) -> Option<i128> {
+    match num.as_i64() {
+        Some(n) => Some(n as i128),
+        None => num.as_u64().map(|n| n as i128),
+    }
+}
 
 pub struct IntegerMatcher {
-    value: i64,
+    value: Decimal,
+    policy: Option<NumericPolicy>,
 }
 
 impl IntegerMatcher {
-    pub fn new(value: i64) -> Self {
-        Self { value }
+    pub fn new(value: impl Into<i128>) -> Self {
+        Self {
+            value: Decimal::from(value.into()),
+            policy: None,
+        }
+    }
+
+    /// Uses this policy instead of [`NumericPolicy::current`] when the value is a float.
+    pub fn policy(mut self, policy: NumericPolicy) -> Self {
+        self.policy = Some(policy);
+        self
     }
 }
 
@@ -16,18 +35,36 @@ impl JsonMatcher for IntegerMatcher {
     fn json_matches(&self, value: &Value) -> Vec<JsonMatcherError> {
         match value {
             Value::Number(num) => {
-                let Some(actual) = num.as_i64() else {
+                let policy = self.policy.unwrap_or_else(NumericPolicy::current);
+                let actual = match Decimal::from_number(num) {
+                    Ok(actual) => actual,
+                    Err(error) => return vec![error],
+                };
+                if policy == NumericPolicy::Lenient {
+                    return match actual == self.value {
+                        true => vec![],
+                        false => vec![JsonMatcherError::at_root(format!(
+                            "Expected number {} but got {}",
+                            self.value, num
+                        ))],
+                    };
+                }
+                let integral = match policy {
+                    NumericPolicy::Strict => !is_float(num),
+                    _ => actual.is_integer(),
+                };
+                if !integral {
                     return vec![JsonMatcherError::at_root(format!(
                         "Expected integer {} but got float {}",
                         self.value, num
                     ))];
-                };
+                }
                 if actual == self.value {
                     vec![]
                 } else {
                     vec![JsonMatcherError::at_root(format!(
                         "Expected integer {} but got {}",
-                        self.value, actual
+                        self.value, num
                     ))]
                 }
             }
@@ -38,19 +75,19 @@ impl JsonMatcher for IntegerMatcher {
 
 impl JsonMatcher for i8 {
     fn json_matches(&self, value: &Value) -> Vec<JsonMatcherError> {
-        IntegerMatcher::new(*self as i64).json_matches(value)
+        IntegerMatcher::new(*self).json_matches(value)
     }
 }
 
 impl JsonMatcher for i16 {
     fn json_matches(&self, value: &Value) -> Vec<JsonMatcherError> {
-        IntegerMatcher::new(*self as i64).json_matches(value)
+        IntegerMatcher::new(*self).json_matches(value)
     }
 }
 
 impl JsonMatcher for i32 {
     fn json_matches(&self, value: &Value) -> Vec<JsonMatcherError> {
-        IntegerMatcher::new(*self as i64).json_matches(value)
+        IntegerMatcher::new(*self).json_matches(value)
     }
 }
 
@@ -60,51 +97,111 @@ impl JsonMatcher for i64 {
     }
 }
 
+impl JsonMatcher for i128 {
+    fn json_matches(&self, value: &Value) -> Vec<JsonMatcherError> {
+        IntegerMatcher::new(*self).json_matches(value)
+    }
+}
+
+impl JsonMatcher for isize {
+    fn json_matches(&self, value: &Value) -> Vec<JsonMatcherError> {
+        IntegerMatcher::new(*self as i128).json_matches(value)
+    }
+}
+
 impl JsonMatcher for u8 {
     fn json_matches(&self, value: &Value) -> Vec<JsonMatcherError> {
-        IntegerMatcher::new(*self as i64).json_matches(value)
+        IntegerMatcher::new(*self).json_matches(value)
     }
 }
 
 impl JsonMatcher for u16 {
     fn json_matches(&self, value: &Value) -> Vec<JsonMatcherError> {
-        IntegerMatcher::new(*self as i64).json_matches(value)
+        IntegerMatcher::new(*self).json_matches(value)
     }
 }
 
 impl JsonMatcher for u32 {
     fn json_matches(&self, value: &Value) -> Vec<JsonMatcherError> {
-        IntegerMatcher::new(*self as i64).json_matches(value)
+        IntegerMatcher::new(*self).json_matches(value)
+    }
+}
+
+impl JsonMatcher for u64 {
+    fn json_matches(&self, value: &Value) -> Vec<JsonMatcherError> {
+        IntegerMatcher::new(*self).json_matches(value)
+    }
+}
+
+impl JsonMatcher for usize {
+    fn json_matches(&self, value: &Value) -> Vec<JsonMatcherError> {
+        IntegerMatcher::new(*self as i128).json_matches(value)
+    }
+}
+
+impl JsonMatcher for u128 {
+    fn json_matches(&self, value: &Value) -> Vec<JsonMatcherError> {
+        IntegerMatcher {
+            value: Decimal::from(*self),
+            policy: None,
+        }
+        .json_matches(value)
     }
 }
 
 pub struct NumberMatcher {
     number: f64,
+    decimal: Option<Decimal>,
+    policy: Option<NumericPolicy>,
 }
 
 impl NumberMatcher {
     pub fn new(value: f64) -> Self {
-        Self { number: value }
+        Self {
+            number: value,
+            decimal: Decimal::from_f64(value),
+            policy: None,
+        }
+    }
+
+    /// Uses this policy instead of [`NumericPolicy::current`] to word mismatches.
+    pub fn policy(mut self, policy: NumericPolicy) -> Self {
+        self.policy = Some(policy);
+        self
+    }
+
+    /// Matches the exact value of `num`, which may not fit an `f64`.
+    pub(crate) fn from_number(num: &Number) -> Self {
+        Self {
+            number: num.as_f64().unwrap_or(f64::NAN),
+            decimal: Decimal::from_number(num).ok(),
+            policy: None,
+        }
     }
 }
 
 impl JsonMatcher for NumberMatcher {
     fn json_matches(&self, value: &Value) -> Vec<JsonMatcherError> {
+        let kind = match self.policy.unwrap_or_else(NumericPolicy::current) {
+            NumericPolicy::Lenient => "number",
+            _ => "float",
+        };
         match value {
             Value::Number(num) => {
-                let Some(actual) = num.as_f64() else {
-                    return vec![JsonMatcherError::at_root(format!(
-                        "Expected float {} but got integer {}",
-                        self.number, num
-                    ))];
+                let actual = match Decimal::from_number(num) {
+                    Ok(actual) => actual,
+                    Err(error) => return vec![error],
                 };
-                if actual == self.number {
-                    vec![]
-                } else {
-                    vec![JsonMatcherError::at_root(format!(
-                        "Expected float {} but got {}",
-                        self.number, actual
-                    ))]
+                match &self.decimal {
+                    Some(expected) if actual == *expected => vec![],
+                    Some(expected) => vec![JsonMatcherError::at_root(format!(
+                        "Expected {} {} but got {}",
+                        kind, expected, num
+                    ))],
+                    None => vec![JsonMatcherError::at_root(format!(
+                        "Expected {} {} but got {}",
+                        kind, self.number, num
+                    ))],
                 }
             }
             _ => vec![JsonMatcherError::at_root("Value is not a float")],
@@ -126,7 +223,7 @@ impl JsonMatcher for f64 {
 
 #[cfg(test)]
 mod tests {
-    use serde_json::Number;
+    use serde_json::json;
 
     use crate::assert_jm;
 
@@ -173,6 +270,51 @@ Actual:
         );
     }
 
+    #[test]
+    fn test_integer_matcher_policies() {
+        let strict = IntegerMatcher::new(30).policy(NumericPolicy::Strict);
+        assert_eq!(
+            strict.json_matches(&json!(30.0)),
+            vec![JsonMatcherError::at_root(
+                "Expected integer 30 but got float 30.0"
+            )]
+        );
+
+        let integral = IntegerMatcher::new(30).policy(NumericPolicy::IntegralFloatsAllowed);
+        assert_jm!(json!(30.0), integral);
+        assert_jm!(json!(30), integral);
+        assert_eq!(
+            integral.json_matches(&json!(30.5)),
+            vec![JsonMatcherError::at_root(
+                "Expected integer 30 but got float 30.5"
+            )]
+        );
+        assert_eq!(
+            integral.json_matches(&json!(31.0)),
+            vec![JsonMatcherError::at_root(
+                "Expected integer 30 but got 31.0"
+            )]
+        );
+
+        let lenient = IntegerMatcher::new(30).policy(NumericPolicy::Lenient);
+        assert_jm!(json!(30.0), lenient);
+        assert_eq!(
+            lenient.json_matches(&json!(30.5)),
+            vec![JsonMatcherError::at_root("Expected number 30 but got 30.5")]
+        );
+        assert_eq!(
+            NumberMatcher::new(30.5)
+                .policy(NumericPolicy::Lenient)
+                .json_matches(&json!(30)),
+            vec![JsonMatcherError::at_root("Expected number 30.5 but got 30")]
+        );
+
+        // the scoped policy applies to inline expectations
+        let _policy = NumericPolicy::IntegralFloatsAllowed.scoped();
+        assert_jm!(json!({ "timeout": 30.0 }), { "timeout": 30 });
+        assert_eq!(strict.json_matches(&json!(30.0)).len(), 1);
+    }
+
     #[test]
     fn test_number_matcher() {
         let get_matcher = || NumberMatcher::new(4.0);
@@ -270,6 +412,54 @@ Actual:
                 .collect::<String>(),
             "$: Expected integer 4 but got 5"
         );
+        // u64
+        assert_eq!(u64::MAX.json_matches(&json!(u64::MAX)), vec![]);
+        assert_eq!(
+            u64::MAX
+                .json_matches(&json!(i64::MAX))
+                .into_iter()
+                .map(|e| e.to_string())
+                .collect::<String>(),
+            "$: Expected integer 18446744073709551615 but got 9223372036854775807"
+        );
+        assert_eq!(
+            4u64.json_matches(&json!(u64::MAX))
+                .into_iter()
+                .map(|e| e.to_string())
+                .collect::<String>(),
+            "$: Expected integer 4 but got 18446744073709551615"
+        );
+        assert_eq!(
+            4u64.json_matches(&json!(4.5))
+                .into_iter()
+                .map(|e| e.to_string())
+                .collect::<String>(),
+            "$: Expected integer 4 but got float 4.5"
+        );
+        // usize
+        assert_eq!(4usize.json_matches(&Value::Number(4.into())), vec![]);
+        // isize
+        assert_eq!((-4isize).json_matches(&json!(-4)), vec![]);
+        // i128
+        assert_eq!(i128::from(i64::MIN).json_matches(&json!(i64::MIN)), vec![]);
+        assert_eq!(
+            i128::MAX
+                .json_matches(&json!(u64::MAX))
+                .into_iter()
+                .map(|e| e.to_string())
+                .collect::<String>(),
+            format!("$: Expected integer {} but got {}", i128::MAX, u64::MAX)
+        );
+        // u128
+        assert_eq!(4u128.json_matches(&Value::Number(4.into())), vec![]);
+        assert_eq!(
+            u128::MAX
+                .json_matches(&json!(4))
+                .into_iter()
+                .map(|e| e.to_string())
+                .collect::<String>(),
+            format!("$: Expected integer {} but got 4", u128::MAX)
+        );
         // f32
         assert_eq!(4f32.json_matches(&Value::Number(4.into())), vec![]);
         assert_eq!(
@@ -0,0 +1,422 @@
+use serde_json::{Number, Value};
+
+use crate::matchers::decimal::Decimal;
+use crate::{JsonMatcher, JsonMatcherError};
+
+mod private {
+    pub trait Sealed {
+        /// The value written as a decimal number, or `None` if it is not finite.
+        fn decimal_string(&self) -> Option<String>;
+    }
+}
+
+/// A value that [`NumericMatcher`] can compare numbers against: any primitive integer or float,
+/// or a `&str` holding a decimal number such as `"0.01"`.
+///
+/// This trait is sealed.
+pub trait NumericValue: private::Sealed {}
+
+macro_rules! impl_numeric_value_for_integers {
+    ($($t:ty),*) => {
+        $(
+            impl private::Sealed for $t {
+                fn decimal_string(&self) -> Option<String> {
+                    Some(self.to_string())
+                }
+            }
+
+            impl NumericValue for $t {}
+        )*
+    };
+}
+
+impl_numeric_value_for_integers!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+macro_rules! impl_numeric_value_for_floats {
+    ($($t:ty),*) => {
+        $(
+            impl private::Sealed for $t {
+                fn decimal_string(&self) -> Option<String> {
+                    // the shortest representation, so that 0.1_f32 means 0.1
+                    self.is_finite().then(|| format!("{:e}", self))
+                }
+            }
+
+            impl NumericValue for $t {}
+        )*
+    };
+}
+
+impl_numeric_value_for_floats!(f32, f64);
+
+impl private::Sealed for &str {
+    fn decimal_string(&self) -> Option<String> {
+        Some(self.to_string())
+    }
+}
+
+impl NumericValue for &str {}
+
+fn to_decimal(value: impl NumericValue) -> Decimal {
+    value
+        .decimal_string()
+        .and_then(|s| Decimal::parse(&s))
+        .expect("Numeric matcher arguments must be finite numbers")
+}
+
+fn too_far_in_scale(num: &Number, other: &Decimal) -> String {
+    format!(
+        "Number {} is too far in scale from {} to compare exactly",
+        num, other
+    )
+}
+
+enum Check {
+    Min(Decimal),
+    Max(Decimal),
+    GreaterThan(Decimal),
+    LessThan(Decimal),
+    Approx(Decimal, Decimal),
+    MultipleOf(Decimal),
+    MaxDecimalPlaces(u64),
+    Integral,
+    Finite,
+    Even,
+    Odd,
+    Positive,
+    Negative,
+    NonNegative,
+    NonPositive,
+}
+
+/// Matches numbers by their range and properties, e.g. prices rounded to cents:
+///
+/// ```
+/// use serde_json::json;
+/// use json_matcher::{assert_jm, NumericMatcher};
+///
+/// assert_jm!(json!({ "price": 12.5 }), {
+///     "price": NumericMatcher::new().positive().max_decimal_places(2)
+/// });
+/// ```
+///
+/// All checks are applied and every failing one is reported. Comparisons use the exact decimal
+/// value of the number, see [`DecimalMatcher`](crate::DecimalMatcher).
+///
+/// Methods taking a [`NumericValue`] panic if it is a non-finite float or a `&str` that is not a
+/// decimal number.
+#[derive(Default)]
+pub struct NumericMatcher {
+    checks: Vec<Check>,
+}
+
+impl NumericMatcher {
+    /// Matches any number.
+    pub fn new() -> Self {
+        Self { checks: vec![] }
+    }
+
+    fn check(mut self, check: Check) -> Self {
+        self.checks.push(check);
+        self
+    }
+
+    /// Requires the number to be greater than or equal to `min`.
+    pub fn min(self, min: impl NumericValue) -> Self {
+        self.check(Check::Min(to_decimal(min)))
+    }
+
+    /// Requires the number to be less than or equal to `max`.
+    pub fn max(self, max: impl NumericValue) -> Self {
+        self.check(Check::Max(to_decimal(max)))
+    }
+
+    /// Requires the number to be strictly greater than `bound`.
+    pub fn greater_than(self, bound: impl NumericValue) -> Self {
+        self.check(Check::GreaterThan(to_decimal(bound)))
+    }
+
+    /// Requires the number to be strictly less than `bound`.
+    pub fn less_than(self, bound: impl NumericValue) -> Self {
+        self.check(Check::LessThan(to_decimal(bound)))
+    }
+
+    /// Requires the number to be between `min` and `max`, inclusive.
+    pub fn between(self, min: impl NumericValue, max: impl NumericValue) -> Self {
+        self.min(min).max(max)
+    }
+
+    /// Requires the number to differ from `target` by at most `tolerance`.
+    pub fn approx(self, target: impl NumericValue, tolerance: impl NumericValue) -> Self {
+        self.check(Check::Approx(
+            to_decimal(target),
+            to_decimal(tolerance).abs(),
+        ))
+    }
+
+    /// Requires the number to be an integer multiple of `step`, e.g. a lot size or `"0.05"`.
+    ///
+    /// Panics if `step` is zero.
+    pub fn multiple_of(self, step: impl NumericValue) -> Self {
+        let step = to_decimal(step);
+        assert!(!step.is_zero(), "multiple_of requires a non-zero step");
+        self.check(Check::MultipleOf(step))
+    }
+
+    /// Requires at most `places` digits after the decimal point, ignoring trailing zeros.
+    pub fn max_decimal_places(self, places: u64) -> Self {
+        self.check(Check::MaxDecimalPlaces(places))
+    }
+
+    /// Requires the number to have no fractional part, whether it is written as an integer or as
+    /// a float like `30.0`.
+    pub fn integral(self) -> Self {
+        self.check(Check::Integral)
+    }
+
+    /// Requires the number to be representable as a finite `f64`. Only numbers beyond the range
+    /// of `f64`, which are kept with the `arbitrary_precision` feature, fail this check.
+    pub fn finite(self) -> Self {
+        self.check(Check::Finite)
+    }
+
+    /// Requires the number to be an even integer.
+    pub fn even(self) -> Self {
+        self.check(Check::Even)
+    }
+
+    /// Requires the number to be an odd integer.
+    pub fn odd(self) -> Self {
+        self.check(Check::Odd)
+    }
+
+    /// Requires the number to be greater than zero.
+    pub fn positive(self) -> Self {
+        self.check(Check::Positive)
+    }
+
+    /// Requires the number to be less than zero.
+    pub fn negative(self) -> Self {
+        self.check(Check::Negative)
+    }
+
+    /// Requires the number to be zero or greater.
+    pub fn non_negative(self) -> Self {
+        self.check(Check::NonNegative)
+    }
+
+    /// Requires the number to be zero or less.
+    pub fn non_positive(self) -> Self {
+        self.check(Check::NonPositive)
+    }
+}
+
+impl JsonMatcher for NumericMatcher {
+    fn json_matches(&self, value: &Value) -> Vec<JsonMatcherError> {
+        let Value::Number(num) = value else {
+            return vec![JsonMatcherError::at_root("Value is not a number")];
+        };
+        let actual = match Decimal::from_number(num) {
+            Ok(actual) => actual,
+            Err(error) => return vec![error],
+        };
+        let zero = Decimal::from(0_i128);
+        self.checks
+            .iter()
+            .filter_map(|check| match check {
+                Check::Min(min) if actual < *min => {
+                    Some(format!("Number {} is less than minimum {}", num, min))
+                }
+                Check::Max(max) if actual > *max => {
+                    Some(format!("Number {} is greater than maximum {}", num, max))
+                }
+                Check::GreaterThan(bound) if actual <= *bound => {
+                    Some(format!("Number {} is not greater than {}", num, bound))
+                }
+                Check::LessThan(bound) if actual >= *bound => {
+                    Some(format!("Number {} is not less than {}", num, bound))
+                }
+                Check::Approx(target, tolerance) => match actual.sub(target) {
+                    Some(difference) if difference.abs() <= *tolerance => None,
+                    Some(_) => Some(format!(
+                        "Number {} is not within {} of {}",
+                        num, tolerance, target
+                    )),
+                    None => Some(too_far_in_scale(num, target)),
+                },
+                Check::MultipleOf(step) => match actual.is_multiple_of(step) {
+                    Some(true) => None,
+                    Some(false) => Some(format!("Number {} is not a multiple of {}", num, step)),
+                    None => Some(too_far_in_scale(num, step)),
+                },
+                Check::MaxDecimalPlaces(places) if actual.decimal_places() > *places => {
+                    Some(format!(
+                        "Number {} has {} decimal places, more than {}",
+                        num,
+                        actual.decimal_places(),
+                        places
+                    ))
+                }
+                Check::Integral if !actual.is_integer() => {
+                    Some(format!("Number {} has a fractional part", num))
+                }
+                Check::Finite if !num.as_f64().is_some_and(f64::is_finite) => {
+                    Some(format!("Number {} is not a finite 64-bit float", num))
+                }
+                Check::Even if !actual.is_integer() || !actual.is_even() => {
+                    Some(format!("Number {} is not even", num))
+                }
+                Check::Odd if !actual.is_integer() || actual.is_even() => {
+                    Some(format!("Number {} is not odd", num))
+                }
+                Check::Positive if actual <= zero => {
+                    Some(format!("Number {} is not positive", num))
+                }
+                Check::Negative if actual >= zero => {
+                    Some(format!("Number {} is not negative", num))
+                }
+                Check::NonNegative if actual < zero => Some(format!("Number {} is negative", num)),
+                Check::NonPositive if actual > zero => Some(format!("Number {} is positive", num)),
+                _ => None,
+            })
+            .map(JsonMatcherError::at_root)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::assert_jm;
+
+    use super::*;
+
+    #[test]
+    fn test_numeric_ranges() {
+        let matcher = NumericMatcher::new().between(1, 10);
+        assert_jm!(json!(1), matcher);
+        assert_jm!(json!(10.0), matcher);
+        assert_eq!(
+            matcher.json_matches(&json!(0.5)),
+            vec![JsonMatcherError::at_root(
+                "Number 0.5 is less than minimum 1"
+            )]
+        );
+        assert_eq!(
+            matcher.json_matches(&json!(u64::MAX)),
+            vec![JsonMatcherError::at_root(
+                "Number 18446744073709551615 is greater than maximum 10"
+            )]
+        );
+        let matcher = NumericMatcher::new().greater_than(0).less_than("0.5");
+        assert_jm!(json!(0.25), matcher);
+        assert_eq!(
+            matcher.json_matches(&json!(0)),
+            vec![JsonMatcherError::at_root("Number 0 is not greater than 0")]
+        );
+        assert_eq!(
+            matcher.json_matches(&json!(0.5)),
+            vec![JsonMatcherError::at_root("Number 0.5 is not less than 0.5")]
+        );
+        let matcher = NumericMatcher::new().approx(0.3, 1e-9);
+        assert_jm!(json!(0.1 + 0.2), matcher);
+        assert_eq!(
+            matcher.json_matches(&json!(0.31)),
+            vec![JsonMatcherError::at_root(
+                "Number 0.31 is not within 0.000000001 of 0.3"
+            )]
+        );
+        assert_eq!(
+            matcher.json_matches(&json!("0.3")),
+            vec![JsonMatcherError::at_root("Value is not a number")]
+        );
+    }
+
+    #[test]
+    fn test_numeric_properties() {
+        let price = NumericMatcher::new().positive().max_decimal_places(2);
+        assert_jm!(json!(12.5), price);
+        assert_jm!(json!(3), price);
+        assert_eq!(
+            price.json_matches(&json!(-12.345)),
+            vec![
+                JsonMatcherError::at_root("Number -12.345 is not positive"),
+                JsonMatcherError::at_root("Number -12.345 has 3 decimal places, more than 2"),
+            ]
+        );
+
+        let lots = NumericMatcher::new().multiple_of(25).non_negative();
+        assert_jm!(json!(150), lots);
+        assert_jm!(json!(0), lots);
+        assert_eq!(
+            lots.json_matches(&json!(-10)),
+            vec![
+                JsonMatcherError::at_root("Number -10 is not a multiple of 25"),
+                JsonMatcherError::at_root("Number -10 is negative"),
+            ]
+        );
+        assert_jm!(json!(0.15), NumericMatcher::new().multiple_of("0.05"));
+        // values too far apart in scale are not expanded to compare them
+        assert_eq!(
+            NumericMatcher::new()
+                .multiple_of("1e-999999999")
+                .approx("1e-999999999", 2)
+                .json_matches(&json!(1)),
+            vec![
+                JsonMatcherError::at_root(
+                    "Number 1 is too far in scale from 1e-999999999 to compare exactly"
+                ),
+                JsonMatcherError::at_root(
+                    "Number 1 is too far in scale from 1e-999999999 to compare exactly"
+                ),
+            ]
+        );
+
+        assert_jm!(json!(30.0), NumericMatcher::new().integral().even());
+        assert_eq!(
+            NumericMatcher::new()
+                .integral()
+                .odd()
+                .json_matches(&json!(2.5)),
+            vec![
+                JsonMatcherError::at_root("Number 2.5 has a fractional part"),
+                JsonMatcherError::at_root("Number 2.5 is not odd"),
+            ]
+        );
+        assert_eq!(
+            NumericMatcher::new().even().json_matches(&json!(7)),
+            vec![JsonMatcherError::at_root("Number 7 is not even")]
+        );
+        assert_eq!(
+            NumericMatcher::new()
+                .negative()
+                .non_positive()
+                .json_matches(&json!(1)),
+            vec![
+                JsonMatcherError::at_root("Number 1 is not negative"),
+                JsonMatcherError::at_root("Number 1 is positive"),
+            ]
+        );
+        assert_jm!(json!(1e300), NumericMatcher::new().finite());
+    }
+
+    #[cfg(feature = "arbitrary_precision")]
+    #[test]
+    fn test_numeric_arbitrary_precision() {
+        let value: Value = serde_json::from_str("1e400").unwrap();
+        assert_eq!(
+            NumericMatcher::new().finite().json_matches(&value),
+            vec![JsonMatcherError::at_root(
+                "Number 1e400 is not a finite 64-bit float"
+            )]
+        );
+        let value: Value = serde_json::from_str("12345678901234567890.05").unwrap();
+        assert_jm!(
+            value,
+            NumericMatcher::new()
+                .multiple_of("0.05")
+                .max_decimal_places(2)
+        );
+    }
+}
@@ -0,0 +1,289 @@
+use serde_json::{Number, Value};
+
+use crate::matchers::decimal::Decimal;
+use crate::{JsonMatcher, JsonMatcherError};
+
+/// Matches strings holding a number, as APIs send int64 IDs and decimal amounts
+/// (`"amount": "12.50"`), and applies a number matcher to the parsed value:
+///
+/// ```
+/// use serde_json::json;
+/// use json_matcher::{assert_jm, DecimalMatcher, NumericMatcher, NumericStringMatcher};
+///
+/// assert_jm!(json!({ "amount": "12.50", "quantity": "3" }), {
+///     "amount": NumericStringMatcher::new().matching(DecimalMatcher::new("12.5")),
+///     "quantity": NumericStringMatcher::integer().matching(NumericMatcher::new().between(1, 10))
+/// });
+/// ```
+///
+/// By default the string must be a plain JSON number without exponent, like `"-12.50"`.
+/// Errors about the string's format and errors from the inner matcher are both reported, the
+/// latter prefixed with `Parsed number:`.
+///
+/// The inner matcher is given the exact value of the string. Without this crate's
+/// `arbitrary_precision` feature, a string whose value does not survive parsing into an `f64`,
+/// like `"12.500000000000000001"`, is reported as an error instead of being rounded.
+pub struct NumericStringMatcher {
+    matcher: Option<Box<dyn JsonMatcher>>,
+    integer: bool,
+    allow_plus: bool,
+    allow_exponent: bool,
+    allow_leading_zeros: bool,
+}
+
+impl Default for NumericStringMatcher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl NumericStringMatcher {
+    /// Matches strings holding an integer or a decimal number.
+    pub fn new() -> Self {
+        Self {
+            matcher: None,
+            integer: false,
+            allow_plus: false,
+            allow_exponent: false,
+            allow_leading_zeros: false,
+        }
+    }
+
+    /// Matches strings holding an integer, without a decimal point.
+    pub fn integer() -> Self {
+        Self {
+            integer: true,
+            ..Self::new()
+        }
+    }
+
+    /// Matches the parsed number against `matcher`, e.g. an exact number, a [`NumericMatcher`]
+    /// or a [`DecimalMatcher`].
+    ///
+    /// [`NumericMatcher`]: crate::NumericMatcher
+    /// [`DecimalMatcher`]: crate::DecimalMatcher
+    pub fn matching(mut self, matcher: impl JsonMatcher + 'static) -> Self {
+        self.matcher = Some(Box::new(matcher));
+        self
+    }
+
+    /// Also accepts a leading `+` sign, e.g. `"+5"`.
+    pub fn allow_plus(mut self) -> Self {
+        self.allow_plus = true;
+        self
+    }
+
+    /// Also accepts exponent notation, e.g. `"1.5e3"`.
+    pub fn allow_exponent(mut self) -> Self {
+        self.allow_exponent = true;
+        self
+    }
+
+    /// Also accepts leading zeros, e.g. `"007"`.
+    pub fn allow_leading_zeros(mut self) -> Self {
+        self.allow_leading_zeros = true;
+        self
+    }
+}
+
+impl JsonMatcher for NumericStringMatcher {
+    fn json_matches(&self, value: &Value) -> Vec<JsonMatcherError> {
+        let Some(s) = value.as_str() else {
+            return vec![JsonMatcherError::at_root(
+                "Expected string holding a number",
+            )];
+        };
+        let Some(exact) = Decimal::parse(s) else {
+            return vec![JsonMatcherError::at_root(format!(
+                "Could not parse string \"{}\" as a number",
+                s
+            ))];
+        };
+        let unsigned = s.strip_prefix(['+', '-']).unwrap_or(s);
+        let (mantissa, exponent) = match unsigned.find(['e', 'E']) {
+            Some(i) => (&unsigned[..i], Some(&unsigned[i + 1..])),
+            None => (unsigned, None),
+        };
+        let (integer, fraction) = match mantissa.split_once('.') {
+            Some((integer, fraction)) => (integer, Some(fraction)),
+            None => (mantissa, None),
+        };
+        let mut errors = vec![];
+        if s.starts_with('+') && !self.allow_plus {
+            errors.push(format!("Numeric string \"{}\" has a leading plus sign", s));
+        }
+        if integer.len() > 1 && integer.starts_with('0') && !self.allow_leading_zeros {
+            errors.push(format!("Numeric string \"{}\" has leading zeros", s));
+        }
+        if exponent.is_some() && !self.allow_exponent {
+            errors.push(format!("Numeric string \"{}\" uses exponent notation", s));
+        }
+        if self.integer && (fraction.is_some() || exponent.is_some()) {
+            errors.push(format!("Numeric string \"{}\" is not an integer", s));
+        }
+        if !errors.is_empty() {
+            return errors.into_iter().map(JsonMatcherError::at_root).collect();
+        }
+        let Some(matcher) = &self.matcher else {
+            return vec![];
+        };
+        // rewrite as a JSON number, which allows neither a plus sign nor leading zeros
+        let sign = if s.starts_with('-') { "-" } else { "" };
+        let rest = &unsigned[integer.len()..];
+        let integer = match integer.trim_start_matches('0') {
+            "" => "0",
+            trimmed => trimmed,
+        };
+        let json = format!("{}{}{}", sign, integer, rest);
+        let Ok(number) = serde_json::from_str::<Number>(&json) else {
+            return vec![JsonMatcherError::at_root(format!(
+                "Numeric string \"{}\" is out of range",
+                s
+            ))];
+        };
+        // without arbitrary_precision, non-integers are parsed into an f64, which may round them
+        if Decimal::from_number(&number).ok() != Some(exact) {
+            return vec![JsonMatcherError::at_root(format!(
+                "Numeric string \"{}\" cannot be parsed exactly without the arbitrary_precision feature",
+                s
+            ))];
+        }
+        let number = Value::Number(number);
+        matcher
+            .json_matches(&number)
+            .into_iter()
+            .map(|JsonMatcherError { path, message }| JsonMatcherError {
+                path,
+                message: format!("Parsed number: {}", message),
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::{assert_jm, DecimalMatcher, NumericMatcher};
+
+    use super::*;
+
+    #[test]
+    fn test_numeric_string_formats() {
+        let matcher = NumericStringMatcher::new();
+        for valid in ["0", "-12", "12.50", "0.001", "18446744073709551616"] {
+            assert_jm!(json!(valid), matcher);
+        }
+        assert_eq!(
+            matcher.json_matches(&json!("+007e3")),
+            vec![
+                JsonMatcherError::at_root("Numeric string \"+007e3\" has a leading plus sign"),
+                JsonMatcherError::at_root("Numeric string \"+007e3\" has leading zeros"),
+                JsonMatcherError::at_root("Numeric string \"+007e3\" uses exponent notation"),
+            ]
+        );
+        assert_jm!(
+            json!("+007e3"),
+            NumericStringMatcher::new()
+                .allow_plus()
+                .allow_leading_zeros()
+                .allow_exponent()
+        );
+        for invalid in ["", "12,5", ".5", "5.", " 5", "0x10", "NaN"] {
+            assert_eq!(
+                matcher.json_matches(&json!(invalid)),
+                vec![JsonMatcherError::at_root(format!(
+                    "Could not parse string \"{}\" as a number",
+                    invalid
+                ))]
+            );
+        }
+        assert_eq!(
+            NumericStringMatcher::integer().json_matches(&json!("12.0")),
+            vec![JsonMatcherError::at_root(
+                "Numeric string \"12.0\" is not an integer"
+            )]
+        );
+        assert_eq!(
+            matcher.json_matches(&json!(12.5)),
+            vec![JsonMatcherError::at_root(
+                "Expected string holding a number"
+            )]
+        );
+    }
+
+    #[test]
+    fn test_numeric_string_values() {
+        let amount = NumericStringMatcher::new().matching(DecimalMatcher::new("12.5"));
+        assert_jm!(json!("12.50"), amount);
+        assert_eq!(
+            amount.json_matches(&json!("12.51")),
+            vec![JsonMatcherError::at_root(
+                "Parsed number: Expected decimal 12.5 but got 12.51"
+            )]
+        );
+
+        let id = NumericStringMatcher::integer().matching(9007199254740993_u64);
+        assert_jm!(json!("9007199254740993"), id);
+
+        let range = NumericStringMatcher::new()
+            .allow_plus()
+            .allow_leading_zeros()
+            .matching(NumericMatcher::new().between(1, 10));
+        assert_jm!(json!("+0005"), range);
+        assert_jm!(json!("0.0"), NumericStringMatcher::new().matching(0.0));
+        assert_eq!(
+            range.json_matches(&json!("-0010.5")),
+            vec![JsonMatcherError::at_root(
+                "Parsed number: Number -10.5 is less than minimum 1"
+            )]
+        );
+        let approx = NumericStringMatcher::new().matching(NumericMatcher::new().approx(2.72, 0.01));
+        assert_jm!(json!("2.7182"), approx);
+
+        // the inner matcher sees the exact value, not the nearest f64
+        let amount = NumericStringMatcher::new().matching(DecimalMatcher::new("12.5"));
+        let places =
+            NumericStringMatcher::new().matching(NumericMatcher::new().max_decimal_places(2));
+        #[cfg(not(feature = "arbitrary_precision"))]
+        {
+            assert_eq!(
+                amount.json_matches(&json!("12.500000000000000001")),
+                vec![JsonMatcherError::at_root(
+                    "Numeric string \"12.500000000000000001\" cannot be parsed exactly without the arbitrary_precision feature"
+                )]
+            );
+            assert_eq!(
+                places.json_matches(&json!("0.1000000000000000000001")),
+                vec![JsonMatcherError::at_root(
+                    "Numeric string \"0.1000000000000000000001\" cannot be parsed exactly without the arbitrary_precision feature"
+                )]
+            );
+        }
+        #[cfg(feature = "arbitrary_precision")]
+        {
+            assert_eq!(
+                amount.json_matches(&json!("12.500000000000000001")),
+                vec![JsonMatcherError::at_root(
+                    "Parsed number: Expected decimal 12.5 but got 12.500000000000000001"
+                )]
+            );
+            assert_eq!(
+                places.json_matches(&json!("0.1000000000000000000001")),
+                vec![JsonMatcherError::at_root(
+                    "Parsed number: Number 0.1000000000000000000001 has 22 decimal places, more than 2"
+                )]
+            );
+        }
+        #[cfg(not(feature = "arbitrary_precision"))]
+        assert_eq!(
+            NumericStringMatcher::new()
+                .allow_exponent()
+                .matching(NumericMatcher::new())
+                .json_matches(&json!("1e400")),
+            vec![JsonMatcherError::at_root(
+                "Numeric string \"1e400\" is out of range"
+            )]
+        );
+    }
+}
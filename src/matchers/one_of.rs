@@ -0,0 +1,161 @@
+use serde_json::Value;
+
+use crate::{JsonMatcher, JsonMatcherError, StringMatcher};
+
+/// Matches values equal to one of a fixed set, such as the states of a status field:
+///
+/// ```
+/// use serde_json::json;
+/// use json_matcher::{assert_jm, OneOfValues, StringMatcher};
+///
+/// assert_jm!(json!({ "status": "active", "priority": 2 }), {
+///     "status": StringMatcher::one_of(["pending", "active", "closed"]),
+///     "priority": OneOfValues::new([1, 2, 3])
+/// });
+/// ```
+///
+/// On failure the allowed values are listed, and for strings the closest allowed string is
+/// suggested if it is only a few edits away, as in
+/// `Expected one of "pending", "active", "closed" but got "actve", did you mean "active"?`.
+pub struct OneOfValues {
+    values: Vec<Value>,
+}
+
+impl OneOfValues {
+    /// Panics if `values` is empty, as no value could match.
+    pub fn new<I: IntoIterator<Item = T>, T: Into<Value>>(values: I) -> Self {
+        let values: Vec<Value> = values.into_iter().map(Into::into).collect();
+        assert!(
+            !values.is_empty(),
+            "OneOfValues requires at least one value"
+        );
+        Self { values }
+    }
+
+    /// The allowed string closest to `actual`, if it is close enough to be a likely typo.
+    fn suggestion(&self, actual: &str) -> Option<&str> {
+        let lowercase = actual.to_lowercase();
+        self.values
+            .iter()
+            .filter_map(Value::as_str)
+            .map(|allowed| (edit_distance(&lowercase, &allowed.to_lowercase()), allowed))
+            .filter(|(distance, allowed)| {
+                *distance <= 2.max(allowed.chars().count().max(actual.chars().count()) / 3)
+            })
+            .min_by_key(|(distance, _)| *distance)
+            .map(|(_, allowed)| allowed)
+    }
+}
+
+impl StringMatcher {
+    /// Matches strings equal to one of `values`.
+    ///
+    /// Panics if `values` is empty.
+    pub fn one_of<I: IntoIterator<Item = T>, T: Into<String>>(values: I) -> OneOfValues {
+        OneOfValues::new(values.into_iter().map(|value| Value::String(value.into())))
+    }
+}
+
+/// The number of single-character insertions, deletions and substitutions between two strings.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, a_char) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, b_char) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(a_char != *b_char);
+            current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
+impl JsonMatcher for OneOfValues {
+    fn json_matches(&self, value: &Value) -> Vec<JsonMatcherError> {
+        if self
+            .values
+            .iter()
+            .any(|allowed| allowed.json_matches(value).is_empty())
+        {
+            return vec![];
+        }
+        let allowed = self
+            .values
+            .iter()
+            .map(|allowed| allowed.to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+        let suggestion = match value.as_str().and_then(|actual| self.suggestion(actual)) {
+            Some(suggestion) => format!(", did you mean {}?", Value::from(suggestion)),
+            None => String::new(),
+        };
+        vec![JsonMatcherError::at_root(format!(
+            "Expected one of {} but got {}{}",
+            allowed, value, suggestion
+        ))]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::assert_jm;
+
+    use super::*;
+
+    #[test]
+    fn test_edit_distance() {
+        assert_eq!(edit_distance("", ""), 0);
+        assert_eq!(edit_distance("active", "actve"), 1);
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+        assert_eq!(edit_distance("", "abc"), 3);
+    }
+
+    #[test]
+    fn test_one_of_values() {
+        let status = StringMatcher::one_of(["pending", "active", "closed"]);
+        assert_jm!(json!("active"), status);
+        assert_eq!(
+            status.json_matches(&json!("actve")),
+            vec![JsonMatcherError::at_root(
+                "Expected one of \"pending\", \"active\", \"closed\" but got \"actve\", did you mean \"active\"?"
+            )]
+        );
+        assert_eq!(
+            status.json_matches(&json!("CLOSED")),
+            vec![JsonMatcherError::at_root(
+                "Expected one of \"pending\", \"active\", \"closed\" but got \"CLOSED\", did you mean \"closed\"?"
+            )]
+        );
+        assert_eq!(
+            status.json_matches(&json!("archived")),
+            vec![JsonMatcherError::at_root(
+                "Expected one of \"pending\", \"active\", \"closed\" but got \"archived\""
+            )]
+        );
+        assert_eq!(
+            status.json_matches(&json!(null)),
+            vec![JsonMatcherError::at_root(
+                "Expected one of \"pending\", \"active\", \"closed\" but got null"
+            )]
+        );
+
+        let scalars = OneOfValues::new([json!(1), json!(2.5), json!(true), json!(null)]);
+        assert_jm!(json!(2.5), scalars);
+        assert_jm!(json!(null), scalars);
+        assert_eq!(
+            scalars.json_matches(&json!("1")),
+            vec![JsonMatcherError::at_root(
+                "Expected one of 1, 2.5, true, null but got \"1\""
+            )]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "OneOfValues requires at least one value")]
+    fn test_one_of_no_values() {
+        let _ = StringMatcher::one_of(Vec::<String>::new());
+    }
+}
@@ -20,8 +20,10 @@ impl JsonMatcher for StrMatcher<'_> {
                     vec![]
                 } else {
                     vec![JsonMatcherError::at_root(format!(
-                        "Expected string \"{}\" but got \"{}\"",
-                        self.value, actual
+                        "Expected string \"{}\" but got \"{}\"{}",
+                        self.value,
+                        actual,
+                        first_difference(self.value, actual)
                     ))]
                 }
             }
@@ -30,6 +32,37 @@ impl JsonMatcher for StrMatcher<'_> {
     }
 }
 
+/// Strings longer than this many characters are hard to compare by eye, so mismatch messages
+/// point out where they first differ.
+const LONG_STRING: usize = 30;
+
+/// Describes where two strings first differ, or returns an empty string if both are short.
+fn first_difference(expected: &str, actual: &str) -> String {
+    if expected.chars().count() <= LONG_STRING && actual.chars().count() <= LONG_STRING {
+        return String::new();
+    }
+    let mut expected_chars = expected.chars();
+    let mut actual_chars = actual.chars();
+    let mut index = 0;
+    loop {
+        let describe = |c: Option<char>| match c {
+            Some(c) => serde_json::to_string(&c.to_string()).unwrap(),
+            None => "end of string".to_string(),
+        };
+        match (expected_chars.next(), actual_chars.next()) {
+            (e, a) if e == a && e.is_some() => index += 1,
+            (e, a) => {
+                return format!(
+                    ", first difference at index {}: expected {} but got {}",
+                    index,
+                    describe(e),
+                    describe(a)
+                );
+            }
+        }
+    }
+}
+
 impl JsonMatcher for &str {
     fn json_matches(&self, value: &Value) -> Vec<JsonMatcherError> {
         StringMatcher::new(*self).json_matches(value)
@@ -42,21 +75,213 @@ impl JsonMatcher for &String {
     }
 }
 
+enum LengthUnit {
+    Chars,
+    Bytes,
+}
+
+impl LengthUnit {
+    fn len(&self, s: &str) -> usize {
+        match self {
+            LengthUnit::Chars => s.chars().count(),
+            LengthUnit::Bytes => s.len(),
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            LengthUnit::Chars => "characters",
+            LengthUnit::Bytes => "bytes",
+        }
+    }
+}
+
+enum StringCheck {
+    StartsWith(String),
+    EndsWith(String),
+    Contains(String),
+    Len(usize, LengthUnit),
+    MinLen(usize, LengthUnit),
+    MaxLen(usize, LengthUnit),
+    NonEmpty,
+    EqIgnoreCase(String),
+    EqIgnoringWhitespace(String),
+}
+
+/// Matches strings, either exactly with [`new`](StringMatcher::new) or by any combination of
+/// the checks below, starting from [`any`](StringMatcher::any):
+///
+/// ```
+/// use serde_json::json;
+/// use json_matcher::{assert_jm, StringMatcher};
+///
+/// assert_jm!(json!({ "id": "ord_8f2a91" }), {
+///     "id": StringMatcher::any().starts_with("ord_").max_len(32)
+/// });
+/// ```
+///
+/// Every failing check is reported.
 pub struct StringMatcher {
-    value: String,
+    value: Option<String>,
+    checks: Vec<StringCheck>,
 }
 
 impl StringMatcher {
     pub fn new<T: Into<String>>(value: T) -> Self {
         Self {
-            value: value.into(),
+            value: Some(value.into()),
+            checks: vec![],
+        }
+    }
+
+    /// Matches any string.
+    pub fn any() -> Self {
+        Self {
+            value: None,
+            checks: vec![],
         }
     }
+
+    fn check(mut self, check: StringCheck) -> Self {
+        self.checks.push(check);
+        self
+    }
+
+    /// Requires the string to start with `prefix`.
+    pub fn starts_with<T: Into<String>>(self, prefix: T) -> Self {
+        self.check(StringCheck::StartsWith(prefix.into()))
+    }
+
+    /// Requires the string to end with `suffix`.
+    pub fn ends_with<T: Into<String>>(self, suffix: T) -> Self {
+        self.check(StringCheck::EndsWith(suffix.into()))
+    }
+
+    /// Requires the string to contain `needle`.
+    pub fn contains<T: Into<String>>(self, needle: T) -> Self {
+        self.check(StringCheck::Contains(needle.into()))
+    }
+
+    /// Requires the string to be exactly `len` characters long.
+    pub fn exact_len(self, len: usize) -> Self {
+        self.check(StringCheck::Len(len, LengthUnit::Chars))
+    }
+
+    /// Requires the string to be at least `len` characters long.
+    pub fn min_len(self, len: usize) -> Self {
+        self.check(StringCheck::MinLen(len, LengthUnit::Chars))
+    }
+
+    /// Requires the string to be at most `len` characters long.
+    pub fn max_len(self, len: usize) -> Self {
+        self.check(StringCheck::MaxLen(len, LengthUnit::Chars))
+    }
+
+    /// Requires the string to be exactly `len` bytes long in UTF-8.
+    pub fn exact_len_bytes(self, len: usize) -> Self {
+        self.check(StringCheck::Len(len, LengthUnit::Bytes))
+    }
+
+    /// Requires the string to be at least `len` bytes long in UTF-8.
+    pub fn min_len_bytes(self, len: usize) -> Self {
+        self.check(StringCheck::MinLen(len, LengthUnit::Bytes))
+    }
+
+    /// Requires the string to be at most `len` bytes long in UTF-8, e.g. to fit a database column.
+    pub fn max_len_bytes(self, len: usize) -> Self {
+        self.check(StringCheck::MaxLen(len, LengthUnit::Bytes))
+    }
+
+    /// Requires the string not to be empty.
+    pub fn non_empty(self) -> Self {
+        self.check(StringCheck::NonEmpty)
+    }
+
+    /// Requires the string to equal `value` when both are lowercased.
+    pub fn eq_ignore_case<T: Into<String>>(self, value: T) -> Self {
+        self.check(StringCheck::EqIgnoreCase(value.into()))
+    }
+
+    /// Requires the string to equal `value` when leading and trailing whitespace is removed from
+    /// both and every other run of whitespace is replaced by a single space.
+    pub fn eq_ignoring_whitespace<T: Into<String>>(self, value: T) -> Self {
+        self.check(StringCheck::EqIgnoringWhitespace(value.into()))
+    }
+}
+
+fn collapse_whitespace(s: &str) -> String {
+    s.split_whitespace().collect::<Vec<_>>().join(" ")
 }
 
 impl JsonMatcher for StringMatcher {
     fn json_matches(&self, value: &Value) -> Vec<JsonMatcherError> {
-        StrMatcher::new(&self.value).json_matches(value)
+        if let Some(expected) = &self.value {
+            let errors = StrMatcher::new(expected).json_matches(value);
+            if !errors.is_empty() || self.checks.is_empty() {
+                return errors;
+            }
+        }
+        let Value::String(actual) = value else {
+            return vec![JsonMatcherError::at_root("Value is not a string")];
+        };
+        self.checks
+            .iter()
+            .filter_map(|check| match check {
+                StringCheck::StartsWith(prefix) if !actual.starts_with(prefix.as_str()) => Some(
+                    format!("String \"{}\" does not start with \"{}\"", actual, prefix),
+                ),
+                StringCheck::EndsWith(suffix) if !actual.ends_with(suffix.as_str()) => Some(
+                    format!("String \"{}\" does not end with \"{}\"", actual, suffix),
+                ),
+                StringCheck::Contains(needle) if !actual.contains(needle.as_str()) => Some(
+                    format!("String \"{}\" does not contain \"{}\"", actual, needle),
+                ),
+                StringCheck::Len(len, unit) if unit.len(actual) != *len => Some(format!(
+                    "String has {} {} but expected {}",
+                    unit.len(actual),
+                    unit.name(),
+                    len
+                )),
+                StringCheck::MinLen(len, unit) if unit.len(actual) < *len => Some(format!(
+                    "String has {} {}, fewer than minimum of {}",
+                    unit.len(actual),
+                    unit.name(),
+                    len
+                )),
+                StringCheck::MaxLen(len, unit) if unit.len(actual) > *len => Some(format!(
+                    "String has {} {}, more than maximum of {}",
+                    unit.len(actual),
+                    unit.name(),
+                    len
+                )),
+                StringCheck::NonEmpty if actual.is_empty() => Some("String is empty".to_string()),
+                StringCheck::EqIgnoreCase(expected)
+                    if expected.to_lowercase() != actual.to_lowercase() =>
+                {
+                    Some(format!(
+                        "Expected string \"{}\" ignoring case but got \"{}\"{}",
+                        expected,
+                        actual,
+                        first_difference(&expected.to_lowercase(), &actual.to_lowercase())
+                    ))
+                }
+                StringCheck::EqIgnoringWhitespace(expected)
+                    if collapse_whitespace(expected) != collapse_whitespace(actual) =>
+                {
+                    Some(format!(
+                        "Expected string \"{}\" ignoring whitespace but got \"{}\"{}",
+                        expected,
+                        actual,
+                        first_difference(
+                            &collapse_whitespace(expected),
+                            &collapse_whitespace(actual)
+                        )
+                    ))
+                }
+                _ => None,
+            })
+            .map(JsonMatcherError::at_root)
+            .collect()
     }
 }
 
@@ -68,6 +293,8 @@ impl JsonMatcher for String {
 
 #[cfg(test)]
 mod tests {
+    use serde_json::json;
+
     use crate::assert_jm;
 
     use super::*;
@@ -108,6 +335,116 @@ Actual:
         );
     }
 
+    #[test]
+    fn test_long_string_difference() {
+        let expected = "The quick brown fox jumps over the lazy dog";
+        assert_eq!(
+            StringMatcher::new(expected)
+                .json_matches(&json!("The quick brown fox jumps over the lazy cat")),
+            vec![JsonMatcherError::at_root(format!(
+                "Expected string \"{}\" but got \"The quick brown fox jumps over the lazy cat\", \
+                 first difference at index 40: expected \"d\" but got \"c\"",
+                expected
+            ))]
+        );
+        assert_eq!(
+            StringMatcher::new(expected)
+                .json_matches(&json!("The quick brown fox jumps over the lazy"))[0]
+                .message,
+            format!(
+                "Expected string \"{}\" but got \"The quick brown fox jumps over the lazy\", \
+                 first difference at index 39: expected \" \" but got end of string",
+                expected
+            )
+        );
+        assert!(StringMatcher::new(expected)
+            .json_matches(&json!("The quick brown fox jumps over the lazy\tdog"))[0]
+            .message
+            .ends_with("first difference at index 39: expected \" \" but got \"\\t\""));
+        assert!(StringMatcher::new(expected)
+            .json_matches(&json!("The quick brown fox jumps over the \"lazy\" dog"))[0]
+            .message
+            .ends_with("first difference at index 35: expected \"l\" but got \"\\\"\""));
+    }
+
+    #[test]
+    fn test_string_checks() {
+        let matcher = StringMatcher::any()
+            .starts_with("ord_")
+            .ends_with("!")
+            .contains("42");
+        assert_jm!(json!("ord_42!"), matcher);
+        assert_eq!(
+            matcher.json_matches(&json!("inv_7")),
+            vec![
+                JsonMatcherError::at_root("String \"inv_7\" does not start with \"ord_\""),
+                JsonMatcherError::at_root("String \"inv_7\" does not end with \"!\""),
+                JsonMatcherError::at_root("String \"inv_7\" does not contain \"42\""),
+            ]
+        );
+        assert_eq!(
+            matcher.json_matches(&json!(42)),
+            vec![JsonMatcherError::at_root("Value is not a string")]
+        );
+
+        // "héllo" has 5 characters but 6 bytes
+        assert_jm!(
+            json!("héllo"),
+            StringMatcher::any().exact_len(5).exact_len_bytes(6)
+        );
+        assert_eq!(
+            StringMatcher::any()
+                .min_len(6)
+                .max_len_bytes(5)
+                .json_matches(&json!("héllo")),
+            vec![
+                JsonMatcherError::at_root("String has 5 characters, fewer than minimum of 6"),
+                JsonMatcherError::at_root("String has 6 bytes, more than maximum of 5"),
+            ]
+        );
+        assert_eq!(
+            StringMatcher::any()
+                .exact_len(3)
+                .min_len_bytes(1)
+                .max_len(10)
+                .non_empty()
+                .json_matches(&json!("")),
+            vec![
+                JsonMatcherError::at_root("String has 0 characters but expected 3"),
+                JsonMatcherError::at_root("String has 0 bytes, fewer than minimum of 1"),
+                JsonMatcherError::at_root("String is empty"),
+            ]
+        );
+
+        assert_jm!(json!("ÉTÉ"), StringMatcher::any().eq_ignore_case("été"));
+        assert_eq!(
+            StringMatcher::any()
+                .eq_ignore_case("Hello")
+                .json_matches(&json!("help")),
+            vec![JsonMatcherError::at_root(
+                "Expected string \"Hello\" ignoring case but got \"help\""
+            )]
+        );
+        assert_jm!(
+            json!("  hello \n\t world "),
+            StringMatcher::any().eq_ignoring_whitespace("hello world")
+        );
+        assert_eq!(
+            StringMatcher::any()
+                .eq_ignoring_whitespace("hello world")
+                .json_matches(&json!("helloworld")),
+            vec![JsonMatcherError::at_root(
+                "Expected string \"hello world\" ignoring whitespace but got \"helloworld\""
+            )]
+        );
+
+        // checks also apply after an exact match
+        assert_eq!(
+            StringMatcher::new("").non_empty().json_matches(&json!("")),
+            vec![JsonMatcherError::at_root("String is empty")]
+        );
+    }
+
     #[test]
     fn test_raw_implementations() {
         assert_eq!(
@@ -1,5 +1,6 @@
 use serde_json::Value;
 
+use crate::matchers::number::integer_value;
 use crate::{
     ArrayMatcherRefs, BooleanMatcher, IntegerMatcher, JsonMatcher, JsonMatcherError, NullMatcher,
     NumberMatcher, ObjectMatcherRefs, StrMatcher,
@@ -10,9 +11,9 @@ impl JsonMatcher for Value {
         match self {
             Value::Null => NullMatcher::new().json_matches(value),
             Value::Bool(x) => BooleanMatcher::exact(*x).json_matches(value),
-            Value::Number(number) => match number.as_i64() {
+            Value::Number(number) => match integer_value(number) {
                 Some(integer) => IntegerMatcher::new(integer).json_matches(value),
-                None => NumberMatcher::new(number.as_f64().unwrap()).json_matches(value),
+                None => NumberMatcher::from_number(number).json_matches(value),
             },
             Value::String(x) => StrMatcher::new(x).json_matches(value),
             Value::Array(vec) => {
@@ -73,6 +74,11 @@ mod tests {
             Value::Number(Number::from(1)).json_matches(&Value::Number(Number::from(2))),
             IntegerMatcher::new(1).json_matches(&Value::Number(Number::from(2)))
         );
+        assert_eq!(
+            json!(u64::MAX).json_matches(&json!(u64::MAX - 1)),
+            IntegerMatcher::new(u64::MAX).json_matches(&json!(u64::MAX - 1))
+        );
+        assert_eq!(json!(u64::MAX).json_matches(&json!(u64::MAX)), vec![]);
         // string
         assert_eq!(
             Value::String("hello".to_string()).json_matches(&Value::String("hello".to_string())),
@@ -0,0 +1,97 @@
+use serde_json::Value;
+
+use crate::{JsonMatcher, JsonMatcherError};
+
+const DEFAULT_ALPHABET: &str = "_-0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// Matches strings holding a NanoID, by default 21 characters of the URL-safe alphabet
+/// `A-Za-z0-9_-`, e.g. `"V1StGXR8_Z5jdHi6B-myT"`.
+pub struct NanoIdMatcher {
+    alphabet: String,
+    length: usize,
+}
+
+impl Default for NanoIdMatcher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl NanoIdMatcher {
+    pub fn new() -> Self {
+        Self {
+            alphabet: DEFAULT_ALPHABET.to_string(),
+            length: 21,
+        }
+    }
+
+    /// Requires the ID to be `length` characters long.
+    pub fn exact_len(mut self, length: usize) -> Self {
+        self.length = length;
+        self
+    }
+
+    /// Requires the ID to only use characters of `alphabet`.
+    pub fn alphabet<T: Into<String>>(mut self, alphabet: T) -> Self {
+        self.alphabet = alphabet.into();
+        self
+    }
+}
+
+impl JsonMatcher for NanoIdMatcher {
+    fn json_matches(&self, value: &Value) -> Vec<JsonMatcherError> {
+        let Some(s) = value.as_str() else {
+            return vec![JsonMatcherError::at_root("Expected string for NanoID")];
+        };
+        let mut errors = vec![];
+        let length = s.chars().count();
+        if length != self.length {
+            errors.push(JsonMatcherError::at_root(format!(
+                "Expected NanoID of {} characters but got {}",
+                self.length, length
+            )));
+        }
+        if let Some(c) = s.chars().find(|&c| !self.alphabet.contains(c)) {
+            errors.push(JsonMatcherError::at_root(format!(
+                "NanoID has character `{}` outside its alphabet",
+                c
+            )));
+        }
+        errors
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::assert_jm;
+
+    use super::*;
+
+    #[test]
+    fn test_nanoid_matcher() {
+        assert_jm!(json!("V1StGXR8_Z5jdHi6B-myT"), NanoIdMatcher::new());
+        assert_eq!(
+            NanoIdMatcher::new().json_matches(&json!("V1StGXR8_Z5jdHi6B+my")),
+            vec![
+                JsonMatcherError::at_root("Expected NanoID of 21 characters but got 20"),
+                JsonMatcherError::at_root("NanoID has character `+` outside its alphabet"),
+            ]
+        );
+        let matcher = NanoIdMatcher::new()
+            .alphabet("0123456789abcdef")
+            .exact_len(10);
+        assert_jm!(json!("4f90d13a42"), matcher);
+        assert_eq!(
+            matcher.json_matches(&json!("4F90D13A42")),
+            vec![JsonMatcherError::at_root(
+                "NanoID has character `F` outside its alphabet"
+            )]
+        );
+        assert_eq!(
+            matcher.json_matches(&json!(4)),
+            vec![JsonMatcherError::at_root("Expected string for NanoID")]
+        );
+    }
+}
@@ -0,0 +1,138 @@
+use std::cell::RefCell;
+use std::sync::{OnceLock, RwLock};
+
+use crate::scope::next_scope_id;
+
+static GLOBAL: RwLock<Option<NumericPolicy>> = RwLock::new(None);
+static FROM_ENV: OnceLock<Result<NumericPolicy, String>> = OnceLock::new();
+
+thread_local! {
+    static SCOPED: RefCell<Vec<(usize, NumericPolicy)>> = const { RefCell::new(vec![]) };
+}
+
+/// Controls whether integers and floats that are mathematically equal match each other.
+///
+/// JSON producers disagree on how to write whole numbers: a Python service may send `30.0` where a
+/// JavaScript client sends `30`. The policy applies to [`IntegerMatcher`](crate::IntegerMatcher),
+/// [`NumberMatcher`](crate::NumberMatcher), [`FitsMatcher`](crate::FitsMatcher) and the number
+/// literals of inline expectations. Floats are always matched by integers of the same value.
+///
+/// ```
+/// use serde_json::json;
+/// use json_matcher::{assert_jm, NumericPolicy};
+///
+/// let _policy = NumericPolicy::IntegralFloatsAllowed.scoped();
+/// assert_jm!(json!({ "timeout": 30.0 }), { "timeout": 30 });
+/// ```
+///
+/// The policy used by a matcher is, in order of preference: the one given to the matcher itself,
+/// the innermost [`scoped`](NumericPolicy::scoped) policy on the current thread, the policy set
+/// with [`set_global`](NumericPolicy::set_global), or the `JSON_MATCHER_NUMERIC_POLICY`
+/// environment variable (`strict`, `integral_floats_allowed` or `lenient`). The environment
+/// variable is read once; an invalid value makes matchers that rely on it panic.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NumericPolicy {
+    /// Integers only match integers, so `30` does not match `30.0`. This is the default.
+    #[default]
+    Strict,
+    /// Integers also match floats without a fractional part, so `30` matches `30.0` and `3e1`.
+    IntegralFloatsAllowed,
+    /// Numbers match by value alone and errors no longer mention integers or floats.
+    Lenient,
+}
+
+impl NumericPolicy {
+    /// Use this policy for matchers on all threads that have no scoped policy.
+    pub fn set_global(policy: NumericPolicy) {
+        *GLOBAL.write().unwrap_or_else(|e| e.into_inner()) = Some(policy);
+    }
+
+    /// Use this policy for matchers on the current thread until the returned guard is dropped.
+    pub fn scoped(self) -> NumericPolicyGuard {
+        let id = next_scope_id();
+        SCOPED.with(|scoped| scoped.borrow_mut().push((id, self)));
+        NumericPolicyGuard { id }
+    }
+
+    /// The policy that a matcher without its own policy would use on the current thread.
+    pub fn current() -> NumericPolicy {
+        if let Some((_, policy)) = SCOPED.with(|scoped| scoped.borrow().last().copied()) {
+            return policy;
+        }
+        match *GLOBAL.read().unwrap_or_else(|e| e.into_inner()) {
+            Some(policy) => policy,
+            None => FROM_ENV
+                .get_or_init(|| {
+                    Self::from_env(std::env::var("JSON_MATCHER_NUMERIC_POLICY").ok().as_deref())
+                })
+                .clone()
+                .unwrap_or_else(|message| panic!("{}", message)),
+        }
+    }
+
+    fn from_env(var: Option<&str>) -> Result<NumericPolicy, String> {
+        match var {
+            None | Some("strict") => Ok(Self::Strict),
+            Some("integral_floats_allowed") => Ok(Self::IntegralFloatsAllowed),
+            Some("lenient") => Ok(Self::Lenient),
+            Some(other) => Err(format!(
+                "Invalid JSON_MATCHER_NUMERIC_POLICY {:?}, expected strict, integral_floats_allowed or lenient",
+                other
+            )),
+        }
+    }
+}
+
+/// Removes a [`scoped`](NumericPolicy::scoped) numeric policy when dropped.
+#[must_use = "the policy is removed as soon as the guard is dropped"]
+pub struct NumericPolicyGuard {
+    id: usize,
+}
+
+impl Drop for NumericPolicyGuard {
+    fn drop(&mut self) {
+        SCOPED.with(|scoped| scoped.borrow_mut().retain(|(id, _)| *id != self.id));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_numeric_policy() {
+        assert_eq!(NumericPolicy::from_env(None), Ok(NumericPolicy::Strict));
+        assert_eq!(
+            NumericPolicy::from_env(Some("lenient")),
+            Ok(NumericPolicy::Lenient)
+        );
+        assert_eq!(
+            NumericPolicy::from_env(Some("integral_floats_allowed")),
+            Ok(NumericPolicy::IntegralFloatsAllowed)
+        );
+        assert_eq!(
+            NumericPolicy::from_env(Some("loose")),
+            Err("Invalid JSON_MATCHER_NUMERIC_POLICY \"loose\", expected strict, integral_floats_allowed or lenient".to_string())
+        );
+        {
+            let _outer = NumericPolicy::Lenient.scoped();
+            {
+                let _inner = NumericPolicy::IntegralFloatsAllowed.scoped();
+                assert_eq!(
+                    NumericPolicy::current(),
+                    NumericPolicy::IntegralFloatsAllowed
+                );
+            }
+            assert_eq!(NumericPolicy::current(), NumericPolicy::Lenient);
+        }
+        let outer = NumericPolicy::Lenient.scoped();
+        let inner = NumericPolicy::IntegralFloatsAllowed.scoped();
+        drop(outer);
+        assert_eq!(
+            NumericPolicy::current(),
+            NumericPolicy::IntegralFloatsAllowed
+        );
+        drop(inner);
+        assert_eq!(SCOPED.with(|scoped| scoped.borrow().len()), 0);
+    }
+}
@@ -0,0 +1,946 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{OnceLock, RwLock};
+
+use serde_json::Value;
+
+use crate::scope::next_scope_id;
+use crate::{JsonMatcherError, JsonPathElement};
+
+static GLOBAL: RwLock<Option<ReportConfig>> = RwLock::new(None);
+static FROM_ENV: OnceLock<Result<ReportConfig, String>> = OnceLock::new();
+static ACTUAL_FILE_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+thread_local! {
+    static SCOPED: RefCell<Vec<(usize, ReportConfig)>> = const { RefCell::new(vec![]) };
+}
+
+/// Controls how the "Actual" section of an [`assert_jm!`](crate::assert_jm) failure report is
+/// rendered.
+///
+/// By default the whole actual value is pretty-printed. For large documents, the report can
+/// instead be focused on the subtrees around the failing paths, with everything else elided as
+/// `…`, and limited in depth, array length and string length:
+///
+/// ```should_panic
+/// use serde_json::json;
+/// use json_matcher::{assert_jm, ReportConfig};
+///
+/// let _report = ReportConfig::focused().max_array_len(2).scoped();
+/// let actual = json!({
+///     "id": 1,
+///     "items": [{ "sku": "a" }, { "sku": "b" }, { "sku": "c" }],
+///     "tags": ["x", "y", "z"]
+/// });
+/// assert_jm!(actual, {
+///     "id": 1,
+///     "items": [{ "sku": "a" }, { "sku": "b" }, { "sku": "d" }],
+///     "tags": ["x", "y", "z"]
+/// });
+/// // Output:
+/// // Json matcher failed:
+/// //   - $.items.2.sku: Expected string "d" but got "c"
+/// //
+/// // Actual (focused on failing paths):
+/// // {
+/// //   … (1 key),
+/// //   "items": [
+/// //     … (2 items),
+/// //     {
+/// //       "sku": "c"
+/// //     }
+/// //   ],
+/// //   … (1 key)
+/// // }
+/// ```
+///
+/// The configuration used by an assertion is, in order of preference: the innermost
+/// [`scoped`](ReportConfig::scoped) configuration on the current thread, the configuration set
+/// with [`set_global`](ReportConfig::set_global), or one read from these environment variables:
+///
+/// - `JSON_MATCHER_ACTUAL`: `full` (the default) or `focused`
+/// - `JSON_MATCHER_MAX_DEPTH`, `JSON_MATCHER_MAX_ARRAY_LEN`, `JSON_MATCHER_MAX_STRING_LEN`: limits
+/// - `JSON_MATCHER_ACTUAL_FILE`: `1` or `true` to write the full actual value to a temp file
+/// - `JSON_MATCHER_REDACT`: comma-separated [redaction](ReportConfig::redact) patterns
+///
+/// The environment variables are read once; an invalid value makes failing assertions panic with
+/// a message naming the variable.
+///
+/// # Redaction
+///
+/// Values at redacted paths are replaced with `"[REDACTED]"` in the "Actual" section, and the
+/// messages of errors at or below those paths are replaced, since they may quote the value:
+///
+/// ```should_panic
+/// use serde_json::json;
+/// use json_matcher::{assert_jm, ReportConfig};
+///
+/// let _report = ReportConfig::full().redact("authorization").redact("*.secret").scoped();
+/// let actual = json!({
+///     "headers": { "authorization": "Bearer abc123" },
+///     "client": { "id": "web", "secret": "hunter2" }
+/// });
+/// assert_jm!(actual, {
+///     "headers": { "authorization": "Bearer xyz789" },
+///     "client": { "id": "web", "secret": "hunter2" }
+/// });
+/// // Output:
+/// // Json matcher failed:
+/// //   - $.headers.authorization: Value does not match (details redacted)
+/// //
+/// // Actual:
+/// // {
+/// //   "headers": {
+/// //     "authorization": "[REDACTED]"
+/// //   },
+/// //   "client": {
+/// //     "id": "web",
+/// //     "secret": "[REDACTED]"
+/// //   }
+/// // }
+/// ```
+///
+/// Redactions accumulate: a scoped configuration adds its patterns to those of the enclosing scopes
+/// and of the global or environment configuration, unless it was built with
+/// [`without_inherited_redactions`](ReportConfig::without_inherited_redactions).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReportConfig {
+    focus: bool,
+    max_depth: Option<usize>,
+    max_array_len: Option<usize>,
+    max_string_len: Option<usize>,
+    write_actual_to_file: bool,
+    redactions: Vec<String>,
+    inherit_redactions: bool,
+}
+
+impl Default for ReportConfig {
+    fn default() -> Self {
+        Self {
+            focus: false,
+            max_depth: None,
+            max_array_len: None,
+            max_string_len: None,
+            write_actual_to_file: false,
+            redactions: vec![],
+            inherit_redactions: true,
+        }
+    }
+}
+
+impl ReportConfig {
+    /// Print the complete actual value. This is the default.
+    pub fn full() -> Self {
+        Self::default()
+    }
+
+    /// Print only the subtrees around failing paths, with default limits of depth 5, 20 array
+    /// items and 200 characters per string within those subtrees.
+    pub fn focused() -> Self {
+        Self {
+            focus: true,
+            max_depth: Some(5),
+            max_array_len: Some(20),
+            max_string_len: Some(200),
+            ..Self::default()
+        }
+    }
+
+    /// Containers nested deeper than this below a printed subtree are collapsed.
+    pub fn max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = Some(max_depth);
+        self
+    }
+
+    /// Only this many leading items of each printed array are shown.
+    pub fn max_array_len(mut self, max_array_len: usize) -> Self {
+        self.max_array_len = Some(max_array_len);
+        self
+    }
+
+    /// Printed strings are cut off after this many characters.
+    pub fn max_string_len(mut self, max_string_len: usize) -> Self {
+        self.max_string_len = Some(max_string_len);
+        self
+    }
+
+    /// Removes the depth, array length and string length limits.
+    pub fn unlimited(mut self) -> Self {
+        self.max_depth = None;
+        self.max_array_len = None;
+        self.max_string_len = None;
+        self
+    }
+
+    /// Also write the complete actual value to a file in the system temp directory and include
+    /// its path in the report.
+    pub fn write_actual_to_file(mut self, write_actual_to_file: bool) -> Self {
+        self.write_actual_to_file = write_actual_to_file;
+        self
+    }
+
+    /// Mask values whose path matches the given pattern.
+    ///
+    /// A pattern is a `.`-separated list of segments, each matched against one element of a path.
+    /// Key segments are compared case-insensitively and may contain `*` wildcards, so `*` on its own
+    /// matches any single key or index (or the root). Patterns starting with `$` must match the
+    /// whole path from the root; other patterns match the end of a path. So `authorization`
+    /// matches that key anywhere, `*.secret` matches any `secret` key, `*_token` matches any key
+    /// ending in `_token` and `$.user.password` matches only that exact path.
+    pub fn redact<T: Into<String>>(mut self, pattern: T) -> Self {
+        self.redactions.push(pattern.into());
+        self
+    }
+
+    /// Mask values whose path matches any of the given [patterns](ReportConfig::redact).
+    pub fn redact_all<I: IntoIterator<Item = T>, T: Into<String>>(mut self, patterns: I) -> Self {
+        self.redactions.extend(patterns.into_iter().map(Into::into));
+        self
+    }
+
+    /// Do not apply the redactions of enclosing scopes or of the global configuration when this
+    /// configuration is [scoped](ReportConfig::scoped).
+    pub fn without_inherited_redactions(mut self) -> Self {
+        self.inherit_redactions = false;
+        self
+    }
+
+    /// Use this configuration for assertions on all threads that have no scoped configuration.
+    pub fn set_global(config: ReportConfig) {
+        *GLOBAL.write().unwrap_or_else(|e| e.into_inner()) = Some(config);
+    }
+
+    /// Use this configuration for assertions on the current thread until the returned guard is
+    /// dropped.
+    pub fn scoped(self) -> ReportConfigGuard {
+        let id = next_scope_id();
+        SCOPED.with(|scoped| scoped.borrow_mut().push((id, self)));
+        ReportConfigGuard { id }
+    }
+
+    /// The configuration that an assertion on the current thread would use.
+    pub fn current() -> ReportConfig {
+        let scoped = SCOPED.with(|scoped| {
+            scoped
+                .borrow()
+                .iter()
+                .map(|(_, config)| config.clone())
+                .collect::<Vec<_>>()
+        });
+        let base = GLOBAL
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+            .unwrap_or_else(|| {
+                FROM_ENV
+                    .get_or_init(|| Self::from_env(|name| std::env::var(name).ok()))
+                    .clone()
+                    .unwrap_or_else(|message| panic!("{}", message))
+            });
+        let Some(mut current) = scoped.last().cloned() else {
+            return base;
+        };
+        let mut inherit = current.inherit_redactions;
+        for outer in scoped.iter().rev().skip(1) {
+            if !inherit {
+                break;
+            }
+            current.redactions.extend(outer.redactions.iter().cloned());
+            inherit = outer.inherit_redactions;
+        }
+        if inherit {
+            current.redactions.extend(base.redactions);
+        }
+        current
+    }
+
+    fn from_env(var: impl Fn(&str) -> Option<String>) -> Result<ReportConfig, String> {
+        let invalid = |name: &str, value: &str, expected: &str| {
+            format!("Invalid {} {:?}, expected {}", name, value, expected)
+        };
+        let mut config = match var("JSON_MATCHER_ACTUAL").as_deref() {
+            None | Some("full") => Self::full(),
+            Some("focused") => Self::focused(),
+            Some(other) => return Err(invalid("JSON_MATCHER_ACTUAL", other, "full or focused")),
+        };
+        let limit = |name: &str| match var(name) {
+            None => Ok(None),
+            Some(value) => value
+                .parse::<usize>()
+                .map(Some)
+                .map_err(|_| invalid(name, &value, "a non-negative integer")),
+        };
+        if let Some(max_depth) = limit("JSON_MATCHER_MAX_DEPTH")? {
+            config.max_depth = Some(max_depth);
+        }
+        if let Some(max_array_len) = limit("JSON_MATCHER_MAX_ARRAY_LEN")? {
+            config.max_array_len = Some(max_array_len);
+        }
+        if let Some(max_string_len) = limit("JSON_MATCHER_MAX_STRING_LEN")? {
+            config.max_string_len = Some(max_string_len);
+        }
+        config.write_actual_to_file = match var("JSON_MATCHER_ACTUAL_FILE").as_deref() {
+            None | Some("0") | Some("false") => false,
+            Some("1") | Some("true") => true,
+            Some(other) => {
+                return Err(invalid(
+                    "JSON_MATCHER_ACTUAL_FILE",
+                    other,
+                    "1, true, 0 or false",
+                ))
+            }
+        };
+        if let Some(redactions) = var("JSON_MATCHER_REDACT") {
+            config = config.redact_all(
+                redactions
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|pattern| !pattern.is_empty()),
+            );
+        }
+        Ok(config)
+    }
+
+    fn is_redacted(&self, path: &[JsonPathElement]) -> bool {
+        (1..=path.len()).any(|len| {
+            self.redactions
+                .iter()
+                .any(|pattern| pattern_matches(pattern, &path[..len]))
+        })
+    }
+
+    /// Returns a copy of the value with all redacted subtrees replaced.
+    fn redact_value(&self, value: &Value, path: &mut Vec<JsonPathElement>) -> Value {
+        if self.is_redacted(path) {
+            return Value::String(REDACTED.to_string());
+        }
+        match value {
+            Value::Array(array) => Value::Array(
+                array
+                    .iter()
+                    .enumerate()
+                    .map(|(index, item)| {
+                        path.push(JsonPathElement::Index(index));
+                        let redacted = self.redact_value(item, path);
+                        path.pop();
+                        redacted
+                    })
+                    .collect(),
+            ),
+            Value::Object(map) => Value::Object(
+                map.iter()
+                    .map(|(key, item)| {
+                        path.push(JsonPathElement::Key(key.clone()));
+                        let redacted = self.redact_value(item, path);
+                        path.pop();
+                        (key.clone(), redacted)
+                    })
+                    .collect(),
+            ),
+            _ => value.clone(),
+        }
+    }
+
+    /// Replaces the messages of errors at redacted paths, which may quote the actual value in any
+    /// form, e.g. escaped or as a single differing character.
+    pub(crate) fn redact_errors(&self, errors: &[JsonMatcherError]) -> Vec<JsonMatcherError> {
+        errors
+            .iter()
+            .map(|error| {
+                if !self.is_redacted(error.path.elements()) {
+                    return error.clone();
+                }
+                JsonMatcherError {
+                    path: error.path.clone(),
+                    message: REDACTED_MESSAGE.to_string(),
+                }
+            })
+            .collect()
+    }
+
+    fn is_limited(&self) -> bool {
+        self.max_depth.is_some() || self.max_array_len.is_some() || self.max_string_len.is_some()
+    }
+}
+
+/// Removes a [`ReportConfig::scoped`] configuration when dropped.
+#[must_use = "the configuration is removed as soon as the guard is dropped"]
+pub struct ReportConfigGuard {
+    id: usize,
+}
+
+impl Drop for ReportConfigGuard {
+    fn drop(&mut self) {
+        SCOPED.with(|scoped| scoped.borrow_mut().retain(|(id, _)| *id != self.id));
+    }
+}
+
+/// Renders the "Actual" section of a failure report.
+pub(crate) fn actual_section(
+    actual: &Value,
+    errors: &[JsonMatcherError],
+    config: &ReportConfig,
+) -> String {
+    let redacted;
+    let actual = if config.redactions.is_empty() {
+        actual
+    } else {
+        redacted = config.redact_value(actual, &mut vec![JsonPathElement::Root]);
+        &redacted
+    };
+    let mut section = if config.focus {
+        let mut root = FocusNode::default();
+        for error in errors {
+            root.insert(error.path.elements());
+        }
+        format!(
+            "Actual (focused on failing paths):\n{}",
+            Renderer { config }.render(actual, Some(&root), 0)
+        )
+    } else if config.is_limited() {
+        format!("Actual:\n{}", Renderer { config }.render(actual, None, 0))
+    } else {
+        format!("Actual:\n{}", serde_json::to_string_pretty(actual).unwrap())
+    };
+    if config.write_actual_to_file {
+        match write_actual_to_file(actual) {
+            Ok(path) => section.push_str(&format!(
+                "\n\nFull actual value written to {}",
+                path.display()
+            )),
+            Err(e) => section.push_str(&format!(
+                "\n\nCould not write full actual value to a file: {}",
+                e
+            )),
+        }
+    }
+    section
+}
+
+fn write_actual_to_file(actual: &Value) -> std::io::Result<PathBuf> {
+    let path = std::env::temp_dir().join(format!(
+        "json-matcher-actual-{}-{}.json",
+        std::process::id(),
+        ACTUAL_FILE_COUNTER.fetch_add(1, Ordering::Relaxed)
+    ));
+    std::fs::write(&path, serde_json::to_string_pretty(actual).unwrap())?;
+    Ok(path)
+}
+
+const REDACTED: &str = "[REDACTED]";
+const REDACTED_MESSAGE: &str = "Value does not match (details redacted)";
+
+fn pattern_matches(pattern: &str, path: &[JsonPathElement]) -> bool {
+    let segments = pattern.split('.').collect::<Vec<&str>>();
+    if segments.first() == Some(&"$") {
+        if segments.len() != path.len() {
+            return false;
+        }
+    } else if segments.len() > path.len() {
+        return false;
+    }
+    let path = &path[path.len() - segments.len()..];
+    segments
+        .iter()
+        .zip(path)
+        .all(|(segment, element)| match element {
+            JsonPathElement::Root => *segment == "$" || *segment == "*",
+            JsonPathElement::Index(index) => glob_matches(segment, &index.to_string()),
+            JsonPathElement::Key(key) => glob_matches(&segment.to_lowercase(), &key.to_lowercase()),
+            JsonPathElement::Decoded(step) => *segment == format!("<{}>", step) || *segment == "*",
+        })
+}
+
+/// Matches text against a pattern in which `*` stands for any sequence of characters.
+fn glob_matches(pattern: &str, text: &str) -> bool {
+    match pattern.split_once('*') {
+        None => pattern == text,
+        Some((prefix, rest)) => {
+            let Some(text) = text.strip_prefix(prefix) else {
+                return false;
+            };
+            text.char_indices()
+                .map(|(i, _)| i)
+                .chain([text.len()])
+                .any(|i| glob_matches(rest, &text[i..]))
+        }
+    }
+}
+
+/// The set of failing paths, as a tree.
+#[derive(Default)]
+struct FocusNode {
+    target: bool,
+    children: HashMap<JsonPathElement, FocusNode>,
+}
+
+impl FocusNode {
+    fn insert(&mut self, path: &[JsonPathElement]) {
+        match path.split_first() {
+            None => self.target = true,
+            Some((JsonPathElement::Root, rest)) => self.insert(rest),
+            Some((element, rest)) => self
+                .children
+                .entry(element.clone())
+                .or_default()
+                .insert(rest),
+        }
+    }
+}
+
+enum Entry {
+    Shown(String),
+    Elided(usize),
+}
+
+struct Renderer<'a> {
+    config: &'a ReportConfig,
+}
+
+impl Renderer<'_> {
+    fn render(&self, value: &Value, focus: Option<&FocusNode>, indent: usize) -> String {
+        match focus {
+            Some(node) if !node.target => self.render_focused(value, node, indent),
+            _ => self.render_full(value, 0, indent),
+        }
+    }
+
+    /// Renders only the children that lead to a failing path.
+    fn render_focused(&self, value: &Value, node: &FocusNode, indent: usize) -> String {
+        match value {
+            Value::Array(array) => {
+                let entries = array
+                    .iter()
+                    .enumerate()
+                    .map(|(index, item)| {
+                        node.children
+                            .get(&JsonPathElement::Index(index))
+                            .map(|child| self.render(item, Some(child), indent + 1))
+                    })
+                    .collect::<Vec<_>>();
+                container("[", "]", "item", group(entries), indent)
+            }
+            Value::Object(map) => {
+                let entries = map
+                    .iter()
+                    .map(|(key, item)| {
+                        node.children
+                            .get(&JsonPathElement::Key(key.clone()))
+                            .map(|child| {
+                                format!(
+                                    "{}: {}",
+                                    serde_json::to_string(key).unwrap(),
+                                    self.render(item, Some(child), indent + 1)
+                                )
+                            })
+                    })
+                    .collect::<Vec<_>>();
+                container("{", "}", "key", group(entries), indent)
+            }
+            _ => self.render_full(value, 0, indent),
+        }
+    }
+
+    /// Renders the whole value, subject to the configured limits.
+    fn render_full(&self, value: &Value, depth: usize, indent: usize) -> String {
+        let too_deep = self.config.max_depth.is_some_and(|max| depth >= max);
+        match value {
+            Value::String(s) => match self.config.max_string_len {
+                Some(max) if s.chars().count() > max => {
+                    let kept = s.chars().take(max).collect::<String>();
+                    let rest = s.chars().count() - max;
+                    let kept = serde_json::to_string(&kept).unwrap();
+                    format!(
+                        "{}… ({} more {})\"",
+                        &kept[..kept.len() - 1],
+                        rest,
+                        plural(rest, "char")
+                    )
+                }
+                _ => serde_json::to_string(s).unwrap(),
+            },
+            Value::Array(array) if !array.is_empty() && too_deep => {
+                format!("[… ({} {})]", array.len(), plural(array.len(), "item"))
+            }
+            Value::Object(map) if !map.is_empty() && too_deep => {
+                format!("{{… ({} {})}}", map.len(), plural(map.len(), "key"))
+            }
+            Value::Array(array) => {
+                let shown = self.config.max_array_len.unwrap_or(array.len());
+                let mut entries = array
+                    .iter()
+                    .take(shown)
+                    .map(|item| Entry::Shown(self.render_full(item, depth + 1, indent + 1)))
+                    .collect::<Vec<_>>();
+                if array.len() > shown {
+                    entries.push(Entry::Elided(array.len() - shown));
+                }
+                container("[", "]", "item", entries, indent)
+            }
+            Value::Object(map) => {
+                let entries = map
+                    .iter()
+                    .map(|(key, item)| {
+                        Entry::Shown(format!(
+                            "{}: {}",
+                            serde_json::to_string(key).unwrap(),
+                            self.render_full(item, depth + 1, indent + 1)
+                        ))
+                    })
+                    .collect::<Vec<_>>();
+                container("{", "}", "key", entries, indent)
+            }
+            _ => serde_json::to_string(value).unwrap(),
+        }
+    }
+}
+
+/// Collapses runs of omitted entries into single elisions.
+fn group(entries: Vec<Option<String>>) -> Vec<Entry> {
+    let mut grouped = vec![];
+    for entry in entries {
+        match (entry, grouped.last_mut()) {
+            (Some(shown), _) => grouped.push(Entry::Shown(shown)),
+            (None, Some(Entry::Elided(count))) => *count += 1,
+            (None, _) => grouped.push(Entry::Elided(1)),
+        }
+    }
+    grouped
+}
+
+fn container(open: &str, close: &str, noun: &str, entries: Vec<Entry>, indent: usize) -> String {
+    if entries.is_empty() {
+        return format!("{}{}", open, close);
+    }
+    let inner = "  ".repeat(indent + 1);
+    let lines = entries
+        .into_iter()
+        .map(|entry| match entry {
+            Entry::Shown(shown) => format!("{}{}", inner, shown),
+            Entry::Elided(count) => format!("{}… ({} {})", inner, count, plural(count, noun)),
+        })
+        .collect::<Vec<_>>();
+    format!(
+        "{}\n{}\n{}{}",
+        open,
+        lines.join(",\n"),
+        "  ".repeat(indent),
+        close
+    )
+}
+
+fn plural(count: usize, noun: &str) -> String {
+    if count == 1 {
+        noun.to_string()
+    } else {
+        format!("{}s", noun)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::test::catch_string_panic;
+    use crate::{assert_jm, JsonPath, StringMatcher};
+
+    use super::*;
+
+    fn errors_at(paths: &[&[JsonPathElement]]) -> Vec<JsonMatcherError> {
+        paths
+            .iter()
+            .map(|path| JsonMatcherError {
+                path: JsonPath::default().extend(JsonPath::from(path.to_vec())),
+                message: "bad".to_string(),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_focused_actual() {
+        let actual = json!({
+            "id": 1,
+            "user": { "name": "Alice", "email": "alice@example.com", "roles": ["a", "b"] },
+            "items": [1, 2, 3, 4, 5],
+            "note": "x"
+        });
+        assert_eq!(
+            actual_section(
+                &actual,
+                &errors_at(&[
+                    &[
+                        JsonPathElement::Key("user".to_string()),
+                        JsonPathElement::Key("email".to_string())
+                    ],
+                    &[
+                        JsonPathElement::Key("items".to_string()),
+                        JsonPathElement::Index(3)
+                    ],
+                ]),
+                &ReportConfig::focused()
+            ),
+            r#"Actual (focused on failing paths):
+{
+  … (1 key),
+  "user": {
+    … (1 key),
+    "email": "alice@example.com",
+    … (1 key)
+  },
+  "items": [
+    … (3 items),
+    4,
+    … (1 item)
+  ],
+  … (1 key)
+}"#
+        );
+        // an error on a container prints the whole container
+        assert_eq!(
+            actual_section(
+                &actual,
+                &errors_at(&[&[JsonPathElement::Key("user".to_string())]]),
+                &ReportConfig::focused().max_depth(1).max_string_len(3)
+            ),
+            r#"Actual (focused on failing paths):
+{
+  … (1 key),
+  "user": {
+    "name": "Ali… (2 more chars)",
+    "email": "ali… (14 more chars)",
+    "roles": [… (2 items)]
+  },
+  … (2 keys)
+}"#
+        );
+    }
+
+    #[test]
+    fn test_limited_full_actual() {
+        let actual = json!({ "items": [[1, 2], [3], [], {}], "s": "abcdef" });
+        assert_eq!(
+            actual_section(
+                &actual,
+                &errors_at(&[&[]]),
+                &ReportConfig::full()
+                    .max_array_len(3)
+                    .max_depth(2)
+                    .max_string_len(4)
+            ),
+            r#"Actual:
+{
+  "items": [
+    [… (2 items)],
+    [… (1 item)],
+    [],
+    … (1 item)
+  ],
+  "s": "abcd… (2 more chars)"
+}"#
+        );
+    }
+
+    #[test]
+    fn test_report_config_from_env() {
+        let env = |vars: &'static [(&'static str, &'static str)]| {
+            move |name: &str| {
+                vars.iter()
+                    .find(|(k, _)| *k == name)
+                    .map(|(_, v)| v.to_string())
+            }
+        };
+        assert_eq!(ReportConfig::from_env(env(&[])), Ok(ReportConfig::full()));
+        assert_eq!(
+            ReportConfig::from_env(env(&[
+                ("JSON_MATCHER_ACTUAL", "focused"),
+                ("JSON_MATCHER_MAX_ARRAY_LEN", "3"),
+                ("JSON_MATCHER_ACTUAL_FILE", "1")
+            ])),
+            Ok(ReportConfig::focused()
+                .max_array_len(3)
+                .write_actual_to_file(true))
+        );
+        assert_eq!(
+            ReportConfig::from_env(env(&[("JSON_MATCHER_MAX_DEPTH", "-1")])),
+            Err(
+                "Invalid JSON_MATCHER_MAX_DEPTH \"-1\", expected a non-negative integer"
+                    .to_string()
+            )
+        );
+        assert_eq!(
+            ReportConfig::from_env(env(&[("JSON_MATCHER_ACTUAL", "short")])),
+            Err("Invalid JSON_MATCHER_ACTUAL \"short\", expected full or focused".to_string())
+        );
+    }
+
+    #[test]
+    fn test_scoped_report_config() {
+        let report = {
+            let _report = ReportConfig::focused().write_actual_to_file(true).scoped();
+            catch_string_panic(|| assert_jm!(json!({ "a": 1, "b": 2 }), { "a": 1, "b": 3 }))
+        };
+        let (head, path) = report
+            .split_once("\n\nFull actual value written to ")
+            .unwrap();
+        assert_eq!(
+            head,
+            r#"
+Json matcher failed:
+  - $.b: Expected integer 3 but got 2
+
+Actual (focused on failing paths):
+{
+  … (1 key),
+  "b": 2
+}"#
+        );
+        assert_eq!(
+            std::fs::read_to_string(path).unwrap(),
+            "{\n  \"a\": 1,\n  \"b\": 2\n}"
+        );
+        std::fs::remove_file(path).unwrap();
+        assert_eq!(SCOPED.with(|scoped| scoped.borrow().len()), 0);
+    }
+
+    #[test]
+    fn test_redaction_patterns() {
+        let path = |keys: &[&str]| {
+            [JsonPathElement::Root]
+                .into_iter()
+                .chain(keys.iter().map(|key| match key.parse::<usize>() {
+                    Ok(index) => JsonPathElement::Index(index),
+                    Err(_) => JsonPathElement::Key(key.to_string()),
+                }))
+                .collect::<Vec<_>>()
+        };
+        assert!(pattern_matches("authorization", &path(&["Authorization"])));
+        assert!(pattern_matches(
+            "authorization",
+            &path(&["headers", "authorization"])
+        ));
+        assert!(pattern_matches("*.secret", &path(&["secret"])));
+        assert!(pattern_matches("*.secret", &path(&["client", "secret"])));
+        assert!(pattern_matches("*_token", &path(&["refresh_token"])));
+        assert!(pattern_matches(
+            "users.*.ssn",
+            &path(&["users", "3", "ssn"])
+        ));
+        assert!(pattern_matches(
+            "$.user.password",
+            &path(&["user", "password"])
+        ));
+        assert!(!pattern_matches("$.password", &path(&["user", "password"])));
+        assert!(!pattern_matches("*_token", &path(&["token"])));
+        assert!(!pattern_matches("a.b.c", &path(&["b", "c"])));
+
+        let config = ReportConfig::full().redact("user");
+        assert!(config.is_redacted(&path(&["user", "name"])));
+        assert!(!config.is_redacted(&path(&["users"])));
+
+        let mut decoded = path(&["token"]);
+        decoded.push(JsonPathElement::Decoded("payload".to_string()));
+        decoded.push(JsonPathElement::Key("sub".to_string()));
+        assert!(pattern_matches("token.<payload>.sub", &decoded));
+        assert!(pattern_matches("*.sub", &decoded));
+        assert_eq!(
+            ReportConfig::full()
+                .redact("token")
+                .redact_errors(&[JsonMatcherError {
+                    path: JsonPath::from(decoded),
+                    message: "Expected string \"bob\" but got \"ann\"".to_string(),
+                }])[0]
+                .message,
+            REDACTED_MESSAGE
+        );
+    }
+
+    #[test]
+    fn test_redacted_report() {
+        let _report = ReportConfig::full()
+            .redact("password")
+            .redact("$.card")
+            .scoped();
+        assert_eq!(
+            catch_string_panic(|| assert_jm!(
+                json!({ "user": { "name": "ann", "password": "hunter2" }, "card": { "pan": 4111, "cvc": 123 } }),
+                {
+                    "user": { "name": "bob", "password": "letmein" },
+                    "card": { "pan": 4111, "cvc": 999 }
+                }
+            )),
+            r#"
+Json matcher failed:
+  - $.card.cvc: Value does not match (details redacted)
+  - $.user.name: Expected string "bob" but got "ann"
+  - $.user.password: Value does not match (details redacted)
+
+Actual:
+{
+  "user": {
+    "name": "ann",
+    "password": "[REDACTED]"
+  },
+  "card": "[REDACTED]"
+}"#
+        );
+    }
+
+    #[test]
+    fn test_redacted_messages_do_not_leak() {
+        let _report = ReportConfig::full().redact("secret").scoped();
+        let reports = [
+            // quoted with JSON escapes
+            catch_string_panic(|| {
+                assert_jm!(json!({ "secret": "p\"w" }), {
+                    "secret": StringMatcher::one_of(["a", "b"])
+                })
+            }),
+            // a single differing character of a long string
+            catch_string_panic(|| {
+                assert_jm!(json!({ "secret": "correct horse battery staple 1 of 3" }), {
+                    "secret": "correct horse battery staple 2 of 3"
+                })
+            }),
+            // the actual value is a substring of the expected one
+            catch_string_panic(|| assert_jm!(json!({ "secret": 123 }), { "secret": 1234 })),
+        ];
+        for report in reports {
+            assert!(report.contains("  - $.secret: Value does not match (details redacted)\n"));
+            assert!(!report.contains("p\\\"w"));
+            assert!(!report.contains("'1'"));
+            assert!(!report.contains("[REDACTED]4"));
+        }
+    }
+
+    #[test]
+    fn test_inherited_redactions() {
+        let _outer = ReportConfig::full().redact("a").scoped();
+        {
+            let _inner = ReportConfig::focused().redact("b").scoped();
+            let current = ReportConfig::current();
+            assert!(current.focus);
+            assert!(current
+                .redactions
+                .starts_with(&["b".to_string(), "a".to_string()]));
+        }
+        {
+            let _inner = ReportConfig::full()
+                .redact("b")
+                .without_inherited_redactions()
+                .scoped();
+            assert_eq!(ReportConfig::current().redactions, vec!["b".to_string()]);
+        }
+    }
+
+    #[test]
+    fn test_guards_dropped_out_of_order() {
+        let outer = ReportConfig::focused().scoped();
+        let inner = ReportConfig::full().max_depth(1).scoped();
+        drop(outer);
+        assert_eq!(ReportConfig::current().max_depth, Some(1));
+        drop(inner);
+        assert_eq!(SCOPED.with(|scoped| scoped.borrow().len()), 0);
+    }
+}
@@ -0,0 +1,14 @@
+use std::cell::Cell;
+
+thread_local! {
+    static NEXT_SCOPE_ID: Cell<usize> = const { Cell::new(0) };
+}
+
+/// A thread-unique id for a scope, so that its guard removes that scope even when guards are
+/// dropped out of order.
+pub(crate) fn next_scope_id() -> usize {
+    NEXT_SCOPE_ID.with(|next| {
+        next.set(next.get() + 1);
+        next.get()
+    })
+}
@@ -0,0 +1,160 @@
+use serde_json::Value;
+
+use crate::{JsonMatcher, JsonMatcherError};
+
+/// The epoch of Twitter snowflake IDs, in unix milliseconds.
+const TWITTER_EPOCH: u64 = 1_288_834_974_657;
+
+/// The epoch of Discord snowflake IDs, in unix milliseconds.
+const DISCORD_EPOCH: u64 = 1_420_070_400_000;
+
+/// Matches Twitter-style snowflake IDs: non-negative 63 bit integers whose upper 41 bits count
+/// milliseconds since an epoch. IDs are accepted as JSON numbers or as strings of digits, since
+/// they often exceed the integers that JavaScript can represent.
+///
+/// The embedded timestamp can be matched with [`timestamp`](SnowflakeMatcher::timestamp).
+pub struct SnowflakeMatcher {
+    #[cfg_attr(not(feature = "datetime"), allow(dead_code))]
+    epoch: u64,
+    #[cfg(feature = "datetime")]
+    timestamp: Option<Box<dyn JsonMatcher>>,
+}
+
+impl Default for SnowflakeMatcher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SnowflakeMatcher {
+    /// Matches snowflake IDs with the Twitter epoch.
+    pub fn new() -> Self {
+        Self {
+            epoch: TWITTER_EPOCH,
+            #[cfg(feature = "datetime")]
+            timestamp: None,
+        }
+    }
+
+    /// Matches snowflake IDs with the Discord epoch.
+    pub fn discord() -> Self {
+        Self::new().epoch(DISCORD_EPOCH)
+    }
+
+    /// Sets the epoch the embedded timestamp counts from, in unix milliseconds.
+    pub fn epoch(mut self, epoch: u64) -> Self {
+        self.epoch = epoch;
+        self
+    }
+
+    /// Matches the embedded timestamp, converted to a unix timestamp in milliseconds, against
+    /// `matcher`.
+    #[cfg(feature = "datetime")]
+    pub fn timestamp(mut self, matcher: impl JsonMatcher + 'static) -> Self {
+        self.timestamp = Some(Box::new(matcher));
+        self
+    }
+}
+
+impl JsonMatcher for SnowflakeMatcher {
+    fn json_matches(&self, value: &Value) -> Vec<JsonMatcherError> {
+        let id = match value {
+            Value::Number(number) => match number.as_u64() {
+                Some(id) => id,
+                None => {
+                    return vec![JsonMatcherError::at_root(
+                        "Snowflake ID must be a non-negative integer",
+                    )];
+                }
+            },
+            Value::String(s) => {
+                let canonical = s.len() == 1 || !s.starts_with('0');
+                match s.parse::<u64>() {
+                    Ok(id) if canonical && s.bytes().all(|b| b.is_ascii_digit()) => id,
+                    _ => {
+                        return vec![JsonMatcherError::at_root(format!(
+                            "Could not parse string \"{}\" as a snowflake ID",
+                            s
+                        ))];
+                    }
+                }
+            }
+            _ => {
+                return vec![JsonMatcherError::at_root(
+                    "Expected number or string for snowflake ID",
+                )];
+            }
+        };
+        if id > i64::MAX as u64 {
+            return vec![JsonMatcherError::at_root("Snowflake ID is out of range")];
+        }
+        #[cfg(feature = "datetime")]
+        if let Some(matcher) = &self.timestamp {
+            let millis = (id >> 22) + self.epoch;
+            return crate::datetime::match_embedded_timestamp(
+                matcher.as_ref(),
+                millis,
+                "Snowflake",
+            );
+        }
+        vec![]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::assert_jm;
+
+    use super::*;
+
+    #[test]
+    fn test_snowflake_matcher() {
+        assert_jm!(json!(1541815603606036480_u64), SnowflakeMatcher::new());
+        assert_jm!(json!("1541815603606036480"), SnowflakeMatcher::new());
+        assert_jm!(json!("0"), SnowflakeMatcher::new());
+        assert_eq!(
+            SnowflakeMatcher::new().json_matches(&json!(-5)),
+            vec![JsonMatcherError::at_root(
+                "Snowflake ID must be a non-negative integer"
+            )]
+        );
+        assert_eq!(
+            SnowflakeMatcher::new().json_matches(&json!(u64::MAX)),
+            vec![JsonMatcherError::at_root("Snowflake ID is out of range")]
+        );
+        for invalid in ["", "012", "+12", "12a"] {
+            assert_eq!(
+                SnowflakeMatcher::new().json_matches(&json!(invalid)),
+                vec![JsonMatcherError::at_root(format!(
+                    "Could not parse string \"{}\" as a snowflake ID",
+                    invalid
+                ))]
+            );
+        }
+        assert_eq!(
+            SnowflakeMatcher::new().json_matches(&json!(true)),
+            vec![JsonMatcherError::at_root(
+                "Expected number or string for snowflake ID"
+            )]
+        );
+    }
+
+    #[cfg(feature = "datetime")]
+    #[test]
+    fn test_snowflake_timestamp() {
+        assert_jm!(
+            json!("1541815603606036480"),
+            SnowflakeMatcher::new().timestamp(1656432460105_i64)
+        );
+        assert_eq!(
+            SnowflakeMatcher::discord()
+                .timestamp(1656432460105_i64)
+                .json_matches(&json!(1541815603606036480_u64)),
+            vec![JsonMatcherError::at_root(
+                "Snowflake timestamp: Expected integer 1656432460105 but got 1787667885448"
+            )]
+        );
+    }
+}
@@ -1,7 +1,11 @@
-use crate::{JsonMatcher, JsonMatcherError};
+use crate::{FitsMatcher, JsonMatcher, JsonMatcherError};
 
+/// Matches integers that fit in a `u16`, or with [`new_allow_strings`](U16Matcher::new_allow_strings)
+/// strings holding one, leading zeros and `+` sign included.
+///
+/// See [`FitsMatcher`] for other integer types.
 pub struct U16Matcher {
-    allow_strings: bool,
+    inner: FitsMatcher<u16>,
 }
 
 impl Default for U16Matcher {
@@ -13,31 +17,22 @@ impl Default for U16Matcher {
 impl U16Matcher {
     pub fn new() -> Self {
         Self {
-            allow_strings: false,
+            inner: FitsMatcher::new(),
         }
     }
 
     pub fn new_allow_strings() -> Self {
         Self {
-            allow_strings: true,
+            inner: FitsMatcher::new_allow_strings()
+                .allow_leading_zeros()
+                .allow_plus_sign(),
         }
     }
 }
 
 impl JsonMatcher for U16Matcher {
     fn json_matches(&self, value: &serde_json::Value) -> Vec<JsonMatcherError> {
-        match self.allow_strings {
-            true => match value.as_str() {
-                Some(s) if s.parse::<u16>().is_ok() => vec![],
-                Some(_) => vec![JsonMatcherError::at_root("Expected number fitting u16")],
-                None => vec![JsonMatcherError::at_root("Expected string fitting u16")],
-            },
-            false => match value.as_i64() {
-                Some(s) if (0..=65535).contains(&s) => vec![],
-                Some(_) => vec![JsonMatcherError::at_root("Integer out of bounds for u16")],
-                None => vec![JsonMatcherError::at_root("Expected number fitting u16")],
-            },
-        }
+        self.inner.json_matches(value)
     }
 }
 
@@ -427,6 +422,10 @@ Actual:
         // Test strings with leading zeros (should still work)
         assert_jm!(Value::String("0042".to_string()), get_matcher());
         assert_jm!(Value::String("00000".to_string()), get_matcher());
+
+        // Test strings with a plus sign (should still work)
+        assert_jm!(Value::String("+42".to_string()), get_matcher());
+        assert_jm!(Value::String("+65535".to_string()), get_matcher());
     }
 
     #[test]
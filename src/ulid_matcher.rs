@@ -0,0 +1,138 @@
+use serde_json::Value;
+
+use crate::{JsonMatcher, JsonMatcherError};
+
+const CROCKFORD_BASE32: &str = "0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+/// Matches strings holding a ULID: 26 characters of Crockford base32, by default in uppercase,
+/// e.g. `"01ARZ3NDEKTSV4RRFFQ69G5FAV"`.
+///
+/// The embedded timestamp can be matched with [`timestamp`](UlidMatcher::timestamp).
+pub struct UlidMatcher {
+    allow_lowercase: bool,
+    #[cfg(feature = "datetime")]
+    timestamp: Option<Box<dyn JsonMatcher>>,
+}
+
+impl Default for UlidMatcher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl UlidMatcher {
+    pub fn new() -> Self {
+        Self {
+            allow_lowercase: false,
+            #[cfg(feature = "datetime")]
+            timestamp: None,
+        }
+    }
+
+    /// Also accepts lowercase characters.
+    pub fn allow_lowercase(mut self) -> Self {
+        self.allow_lowercase = true;
+        self
+    }
+
+    /// Matches the embedded unix timestamp, as a number of milliseconds, against `matcher`.
+    #[cfg(feature = "datetime")]
+    pub fn timestamp(mut self, matcher: impl JsonMatcher + 'static) -> Self {
+        self.timestamp = Some(Box::new(matcher));
+        self
+    }
+}
+
+impl JsonMatcher for UlidMatcher {
+    fn json_matches(&self, value: &Value) -> Vec<JsonMatcherError> {
+        let Some(s) = value.as_str() else {
+            return vec![JsonMatcherError::at_root("Expected string for ULID")];
+        };
+        if s.chars().count() != 26 {
+            return vec![JsonMatcherError::at_root(format!(
+                "Expected ULID of 26 characters but got {}",
+                s.chars().count()
+            ))];
+        }
+        if let Some(c) = s
+            .chars()
+            .find(|c| !CROCKFORD_BASE32.contains(c.to_ascii_uppercase()))
+        {
+            return vec![JsonMatcherError::at_root(format!(
+                "ULID has character `{}` outside Crockford base32",
+                c
+            ))];
+        }
+        if !self.allow_lowercase && s.chars().any(|c| c.is_ascii_lowercase()) {
+            return vec![JsonMatcherError::at_root("ULID has lowercase characters")];
+        }
+        // 26 characters hold 130 bits, of which only the lower 128 are used
+        if s.as_bytes()[0] > b'7' {
+            return vec![JsonMatcherError::at_root("ULID is out of range")];
+        }
+        #[cfg(feature = "datetime")]
+        if let Some(matcher) = &self.timestamp {
+            let millis = s[..10].chars().fold(0_u64, |millis, c| {
+                millis << 5 | CROCKFORD_BASE32.find(c.to_ascii_uppercase()).unwrap() as u64
+            });
+            return crate::datetime::match_embedded_timestamp(matcher.as_ref(), millis, "ULID");
+        }
+        vec![]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::assert_jm;
+
+    use super::*;
+
+    #[test]
+    fn test_ulid_matcher() {
+        let ulid = json!("01ARZ3NDEKTSV4RRFFQ69G5FAV");
+        assert_jm!(ulid, UlidMatcher::new());
+        assert_eq!(
+            UlidMatcher::new().json_matches(&json!("01arz3ndektsv4rrffq69g5fav")),
+            vec![JsonMatcherError::at_root("ULID has lowercase characters")]
+        );
+        assert_jm!(
+            json!("01arz3ndektsv4rrffq69g5fav"),
+            UlidMatcher::new().allow_lowercase()
+        );
+        assert_eq!(
+            UlidMatcher::new().json_matches(&json!("01ARZ3NDEKTSV4RRFFQ69G5FA")),
+            vec![JsonMatcherError::at_root(
+                "Expected ULID of 26 characters but got 25"
+            )]
+        );
+        assert_eq!(
+            UlidMatcher::new().json_matches(&json!("01ARZ3NDEKTSV4RRFFQ69G5FAU")),
+            vec![JsonMatcherError::at_root(
+                "ULID has character `U` outside Crockford base32"
+            )]
+        );
+        assert_eq!(
+            UlidMatcher::new().json_matches(&json!("81ARZ3NDEKTSV4RRFFQ69G5FAV")),
+            vec![JsonMatcherError::at_root("ULID is out of range")]
+        );
+        assert_eq!(
+            UlidMatcher::new().json_matches(&json!(1)),
+            vec![JsonMatcherError::at_root("Expected string for ULID")]
+        );
+    }
+
+    #[cfg(feature = "datetime")]
+    #[test]
+    fn test_ulid_timestamp() {
+        let ulid = json!("01ARZ3NDEKTSV4RRFFQ69G5FAV");
+        assert_jm!(ulid, UlidMatcher::new().timestamp(1469922850259_i64));
+        assert_eq!(
+            UlidMatcher::new().timestamp(0).json_matches(&ulid),
+            vec![JsonMatcherError::at_root(
+                "ULID timestamp: Expected integer 0 but got 1469922850259"
+            )]
+        );
+    }
+}
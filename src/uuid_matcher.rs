@@ -1,6 +1,73 @@
+use serde_json::Value;
+
 use crate::{JsonMatcher, JsonMatcherError};
 
-pub struct UuidMatcher;
+/// The variant field of a UUID, which determines how the rest of its bits are laid out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UuidVariant {
+    /// Reserved for backward compatibility with NCS UUIDs.
+    Ncs,
+    /// The variant of RFC 9562 (formerly RFC 4122), used by all versioned UUIDs.
+    Rfc9562,
+    /// Reserved for backward compatibility with Microsoft GUIDs.
+    Microsoft,
+    /// Reserved for future definition.
+    Reserved,
+}
+
+impl UuidVariant {
+    fn of(bytes: &[u8; 16]) -> Self {
+        match bytes[8] {
+            b if b & 0x80 == 0 => UuidVariant::Ncs,
+            b if b & 0xc0 == 0x80 => UuidVariant::Rfc9562,
+            b if b & 0xe0 == 0xc0 => UuidVariant::Microsoft,
+            _ => UuidVariant::Reserved,
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            UuidVariant::Ncs => "NCS",
+            UuidVariant::Rfc9562 => "RFC 9562",
+            UuidVariant::Microsoft => "Microsoft",
+            UuidVariant::Reserved => "reserved",
+        }
+    }
+}
+
+/// Matches strings holding a UUID, by default in lowercase hyphenated form, e.g.
+/// `"550e8400-e29b-41d4-a716-446655440000"`.
+///
+/// Other forms can be allowed, and the version, variant and embedded timestamp can be checked:
+///
+#[cfg_attr(feature = "datetime", doc = "```")]
+#[cfg_attr(not(feature = "datetime"), doc = "```ignore")]
+/// use chrono::{DateTime, Utc};
+/// use json_matcher::{assert_jm, datetime::EpochTimestampMatcher, UuidMatcher};
+/// use serde_json::json;
+///
+/// let deployed: DateTime<Utc> = "2024-06-01T00:00:00Z".parse().unwrap();
+/// assert_jm!(json!({
+///     "id": "01902f1e-8c7b-7abc-8def-0123456789ab",
+///     "legacy_id": "{6BA7B810-9DAD-11D1-80B4-00C04FD430C8}",
+///     "request_id": "urn:uuid:550e8400-e29b-41d4-a716-446655440000"
+/// }), {
+///     "id": UuidMatcher::v7().timestamp(EpochTimestampMatcher::millis().after(deployed)),
+///     "legacy_id": UuidMatcher::new().allow_braced().allow_uppercase(),
+///     "request_id": UuidMatcher::v4().allow_urn()
+/// });
+/// ```
+pub struct UuidMatcher {
+    version: Option<u8>,
+    variant: Option<UuidVariant>,
+    nil: Option<bool>,
+    allow_uppercase: bool,
+    allow_braced: bool,
+    allow_urn: bool,
+    allow_simple: bool,
+    #[cfg(feature = "datetime")]
+    timestamp: Option<Box<dyn JsonMatcher>>,
+}
 
 impl Default for UuidMatcher {
     fn default() -> Self {
@@ -9,17 +76,339 @@ impl Default for UuidMatcher {
 }
 
 impl UuidMatcher {
+    /// Matches any UUID.
     pub fn new() -> Self {
-        Self
+        Self {
+            version: None,
+            variant: None,
+            nil: None,
+            allow_uppercase: false,
+            allow_braced: false,
+            allow_urn: false,
+            allow_simple: false,
+            #[cfg(feature = "datetime")]
+            timestamp: None,
+        }
+    }
+
+    /// Matches UUIDs of the given version with the RFC 9562 variant.
+    pub fn version(version: u8) -> Self {
+        Self {
+            version: Some(version),
+            variant: Some(UuidVariant::Rfc9562),
+            ..Self::new()
+        }
+    }
+
+    /// Matches random UUIDs.
+    pub fn v4() -> Self {
+        Self::version(4)
+    }
+
+    /// Matches unix timestamp based UUIDs, whose timestamp can be checked with
+    /// [`timestamp`](UuidMatcher::timestamp).
+    pub fn v7() -> Self {
+        Self::version(7)
+    }
+
+    /// Matches only the nil UUID, `00000000-0000-0000-0000-000000000000`.
+    pub fn nil() -> Self {
+        Self {
+            nil: Some(true),
+            ..Self::new()
+        }
+    }
+
+    /// Matches any UUID except the nil UUID.
+    pub fn not_nil() -> Self {
+        Self {
+            nil: Some(false),
+            ..Self::new()
+        }
+    }
+
+    pub fn variant(mut self, variant: UuidVariant) -> Self {
+        self.variant = Some(variant);
+        self
+    }
+
+    /// Also accepts uppercase hex digits.
+    pub fn allow_uppercase(mut self) -> Self {
+        self.allow_uppercase = true;
+        self
+    }
+
+    /// Also accepts UUIDs wrapped in braces, e.g. `{550e8400-e29b-41d4-a716-446655440000}`.
+    pub fn allow_braced(mut self) -> Self {
+        self.allow_braced = true;
+        self
+    }
+
+    /// Also accepts UUIDs in URN form, e.g. `urn:uuid:550e8400-e29b-41d4-a716-446655440000`.
+    pub fn allow_urn(mut self) -> Self {
+        self.allow_urn = true;
+        self
+    }
+
+    /// Also accepts UUIDs without dashes, e.g. `550e8400e29b41d4a716446655440000`.
+    pub fn allow_simple(mut self) -> Self {
+        self.allow_simple = true;
+        self
+    }
+
+    /// Requires the UUID to be of version 7, and matches its embedded unix timestamp, as a number
+    /// of milliseconds, against `matcher`, e.g. an
+    /// [`EpochTimestampMatcher`](crate::datetime::EpochTimestampMatcher).
+    #[cfg(feature = "datetime")]
+    pub fn timestamp(mut self, matcher: impl JsonMatcher + 'static) -> Self {
+        self.timestamp = Some(Box::new(matcher));
+        self
+    }
+
+    /// Parses the string into the UUID's bytes, checking it is in one of the allowed forms.
+    fn parse(&self, s: &str) -> Result<[u8; 16], &'static str> {
+        let mut hex = s;
+        if let Some(inner) = hex.strip_prefix('{').and_then(|s| s.strip_suffix('}')) {
+            if !self.allow_braced {
+                return Err("Braced UUIDs are not allowed");
+            }
+            hex = inner;
+        } else if hex
+            .get(..9)
+            .is_some_and(|prefix| prefix.eq_ignore_ascii_case("urn:uuid:"))
+        {
+            if !self.allow_urn {
+                return Err("URN UUIDs are not allowed");
+            }
+            hex = &hex[9..];
+        }
+        let digits = match hex.len() {
+            36 => {
+                let bytes = hex.as_bytes();
+                if [8, 13, 18, 23].iter().any(|&i| bytes[i] != b'-') {
+                    return Err("Expected valid UUID format");
+                }
+                hex.bytes().filter(|&b| b != b'-').collect::<Vec<u8>>()
+            }
+            32 => {
+                if !self.allow_simple {
+                    return Err("UUIDs without dashes are not allowed");
+                }
+                hex.bytes().collect()
+            }
+            _ => return Err("Expected valid UUID format"),
+        };
+        if digits.len() != 32 || !digits.iter().all(u8::is_ascii_hexdigit) {
+            return Err("Expected valid UUID format");
+        }
+        if !self.allow_uppercase && digits.iter().any(u8::is_ascii_uppercase) {
+            return Err("UUID has uppercase hex digits");
+        }
+        let mut bytes = [0; 16];
+        for (byte, pair) in bytes.iter_mut().zip(digits.chunks(2)) {
+            // both digits were checked to be hex above
+            *byte = u8::from_str_radix(std::str::from_utf8(pair).unwrap(), 16).unwrap();
+        }
+        Ok(bytes)
     }
 }
 
 impl JsonMatcher for UuidMatcher {
-    fn json_matches(&self, value: &serde_json::Value) -> Vec<JsonMatcherError> {
-        match value.as_str() {
-            Some(s) if s.len() == 36 && s.chars().filter(|&c| c == '-').count() == 4 => vec![],
-            Some(_) => vec![JsonMatcherError::at_root("Expected valid UUID format")],
-            None => vec![JsonMatcherError::at_root("Expected string for UUID")],
+    fn json_matches(&self, value: &Value) -> Vec<JsonMatcherError> {
+        let Some(s) = value.as_str() else {
+            return vec![JsonMatcherError::at_root("Expected string for UUID")];
+        };
+        let bytes = match self.parse(s) {
+            Ok(bytes) => bytes,
+            Err(err) => return vec![JsonMatcherError::at_root(err)],
+        };
+        let is_nil = bytes.iter().all(|&b| b == 0);
+        match self.nil {
+            Some(true) if !is_nil => return vec![JsonMatcherError::at_root("Expected nil UUID")],
+            Some(false) if is_nil => {
+                return vec![JsonMatcherError::at_root("Expected non-nil UUID")]
+            }
+            _ => {}
+        }
+        let mut errors = vec![];
+        let variant = UuidVariant::of(&bytes);
+        if let Some(expected) = self.variant {
+            if variant != expected {
+                errors.push(JsonMatcherError::at_root(format!(
+                    "Expected UUID variant {} but got {}",
+                    expected.name(),
+                    variant.name()
+                )));
+            }
+        }
+        let version = bytes[6] >> 4;
+        if let Some(expected) = self.version {
+            if version != expected {
+                errors.push(JsonMatcherError::at_root(format!(
+                    "Expected UUID version {} but got version {}",
+                    expected, version
+                )));
+            }
+        }
+        #[cfg(feature = "datetime")]
+        if let Some(matcher) = &self.timestamp {
+            if version != 7 || variant != UuidVariant::Rfc9562 {
+                errors.push(JsonMatcherError::at_root(format!(
+                    "UUID version {} has no unix timestamp",
+                    version
+                )));
+            } else {
+                let millis = bytes[..6]
+                    .iter()
+                    .fold(0_u64, |millis, &b| millis << 8 | b as u64);
+                errors.extend(crate::datetime::match_embedded_timestamp(
+                    matcher.as_ref(),
+                    millis,
+                    "UUID",
+                ));
+            }
         }
+        errors
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::assert_jm;
+
+    use super::*;
+
+    #[test]
+    fn test_uuid_forms() {
+        let matcher = UuidMatcher::new();
+        assert_jm!(json!("550e8400-e29b-41d4-a716-446655440000"), matcher);
+        for invalid in [
+            "zzzzzzzz-zzzz-zzzz-zzzz-zzzzzzzzzzzz",
+            "550e8400e-29b-41d4-a716-446655440000",
+            "550e8400-e29b-41d4-a716-44665544000",
+            "not-a-uuid",
+            "550e8400-e29b-41d4-a716-4466554400-0",
+        ] {
+            assert_eq!(
+                matcher.json_matches(&json!(invalid)),
+                vec![JsonMatcherError::at_root("Expected valid UUID format")],
+                "{}",
+                invalid
+            );
+        }
+        assert_eq!(
+            matcher.json_matches(&json!(5)),
+            vec![JsonMatcherError::at_root("Expected string for UUID")]
+        );
+        let cases = [
+            (
+                "550E8400-E29B-41D4-A716-446655440000",
+                "UUID has uppercase hex digits",
+                UuidMatcher::new().allow_uppercase(),
+            ),
+            (
+                "{550e8400-e29b-41d4-a716-446655440000}",
+                "Braced UUIDs are not allowed",
+                UuidMatcher::new().allow_braced(),
+            ),
+            (
+                "urn:uuid:550e8400-e29b-41d4-a716-446655440000",
+                "URN UUIDs are not allowed",
+                UuidMatcher::new().allow_urn(),
+            ),
+            (
+                "550e8400e29b41d4a716446655440000",
+                "UUIDs without dashes are not allowed",
+                UuidMatcher::new().allow_simple(),
+            ),
+        ];
+        for (uuid, error, allowing) in cases {
+            assert_eq!(
+                matcher.json_matches(&json!(uuid)),
+                vec![JsonMatcherError::at_root(error)]
+            );
+            assert_jm!(json!(uuid), allowing);
+        }
+        assert_jm!(
+            json!("{550E8400E29B41D4A716446655440000}"),
+            UuidMatcher::new()
+                .allow_braced()
+                .allow_simple()
+                .allow_uppercase()
+        );
+    }
+
+    #[test]
+    fn test_uuid_versions() {
+        let v4 = json!("550e8400-e29b-41d4-a716-446655440000");
+        let v7 = json!("01902f1e-8c7b-7abc-8def-0123456789ab");
+        let nil = json!("00000000-0000-0000-0000-000000000000");
+        assert_jm!(v4, UuidMatcher::v4());
+        assert_jm!(v7, UuidMatcher::v7());
+        assert_jm!(v4, UuidMatcher::version(4).variant(UuidVariant::Rfc9562));
+        assert_eq!(
+            UuidMatcher::v7().json_matches(&v4),
+            vec![JsonMatcherError::at_root(
+                "Expected UUID version 7 but got version 4"
+            )]
+        );
+        assert_eq!(
+            UuidMatcher::v4().json_matches(&json!("550e8400-e29b-41d4-c716-446655440000")),
+            vec![JsonMatcherError::at_root(
+                "Expected UUID variant RFC 9562 but got Microsoft"
+            )]
+        );
+        assert_jm!(nil, UuidMatcher::nil());
+        assert_eq!(
+            UuidMatcher::nil().json_matches(&v4),
+            vec![JsonMatcherError::at_root("Expected nil UUID")]
+        );
+        assert_jm!(v4, UuidMatcher::not_nil());
+        assert_eq!(
+            UuidMatcher::not_nil().json_matches(&nil),
+            vec![JsonMatcherError::at_root("Expected non-nil UUID")]
+        );
+    }
+
+    #[cfg(feature = "datetime")]
+    #[test]
+    fn test_uuid_timestamp() {
+        let v7 = json!("01902f1e-8c7b-7abc-8def-0123456789ab");
+        assert_jm!(v7, UuidMatcher::v7().timestamp(1718777449595_i64));
+        assert_eq!(
+            UuidMatcher::v7()
+                .timestamp(1718777449596_i64)
+                .json_matches(&v7),
+            vec![JsonMatcherError::at_root(
+                "UUID timestamp: Expected integer 1718777449596 but got 1718777449595"
+            )]
+        );
+        assert_eq!(
+            UuidMatcher::new()
+                .timestamp(0)
+                .json_matches(&json!("550e8400-e29b-41d4-a716-446655440000")),
+            vec![JsonMatcherError::at_root(
+                "UUID version 4 has no unix timestamp"
+            )]
+        );
+    }
+
+    #[cfg(feature = "datetime")]
+    #[test]
+    fn test_uuid_timestamp_bounds() {
+        use crate::datetime::EpochTimestampMatcher;
+        use chrono::{DateTime, Utc};
+
+        let t: DateTime<Utc> = "2024-06-20T00:00:00Z".parse().unwrap();
+        assert_eq!(
+            UuidMatcher::v7()
+                .timestamp(EpochTimestampMatcher::millis().after(t))
+                .json_matches(&json!("01902f1e-8c7b-7abc-8def-0123456789ab")),
+            vec![JsonMatcherError::at_root(
+                "UUID timestamp: Timestamp 1718777449595 ms (2024-06-19T06:10:49.595+00:00) is before or equal to lower bound of 2024-06-20T00:00:00+00:00"
+            )]
+        );
     }
 }